@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_core::config::load_params;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::{Answer, PartKind, Solution};
+
+// Same layout the runner uses, dayN/input.txt and dayN/config.toml next to it.
+fn day_dir(day: u32) -> PathBuf
+{
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(format!("day{day}"));
+}
+
+fn bench_part<P>(c: &mut Criterion, day: u32, part: u32, kind: PartKind, input: &String,
+                 parse: impl Fn(&String) -> Result<P, ParseError>, solve: impl Fn(&P) -> Answer)
+{
+    if kind == PartKind::Missing
+    {
+        return;
+    }
+
+    let parsed = match parse(input)
+    {
+        Ok(parsed) => parsed,
+        Err(err) =>
+        {
+            println!("Day {day} part {part}: skipped, couldn't parse input, {err}");
+            return;
+        }
+    };
+
+    // The report reads the medians back from target/criterion/dayNN_partN/{parse,solve}.
+    let mut group = c.benchmark_group(format!("day{day:02}_part{part}"));
+
+    // Some days take seconds, the minimum sample count keeps a full run bearable.
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| parse(black_box(input))));
+    if kind == PartKind::Solver
+    {
+        group.bench_function("solve", |b| b.iter(|| solve(black_box(&parsed))));
+    }
+    group.finish();
+}
+
+// Input and config are read before anything is timed.
+fn bench_day<S: Solution>(c: &mut Criterion)
+{
+    let input_path = day_dir(S::DAY).join("input.txt");
+    let Ok(input) = fs::read_to_string(&input_path) else
+    {
+        println!("Day {}: skipped, no input at {}", S::DAY, input_path.display());
+        return;
+    };
+
+    let params: S::Params = match load_params(&day_dir(S::DAY).join("config.toml"))
+    {
+        Ok(params) => params,
+        Err(err) =>
+        {
+            println!("Day {}: skipped, couldn't parse config, {err}", S::DAY);
+            return;
+        }
+    };
+
+    bench_part(c, S::DAY, 1, S::PART_KINDS[0], &input, |input| S::parse_part1(input, &params), |parsed| S::solve_part1(parsed, &params));
+    bench_part(c, S::DAY, 2, S::PART_KINDS[1], &input, |input| S::parse_part2(input, &params), |parsed| S::solve_part2(parsed, &params));
+}
+
+pub fn calendar_benchmark(c: &mut Criterion)
+{
+    bench_day::<day1::solution::Day1>(c);
+    bench_day::<day2::solution::Day2>(c);
+    bench_day::<day3::solution::Day3>(c);
+    bench_day::<day4::solution::Day4>(c);
+    bench_day::<day5::solution::Day5>(c);
+    bench_day::<day6::solution::Day6>(c);
+    bench_day::<day7::solution::Day7>(c);
+    bench_day::<day8::solution::Day8>(c);
+    bench_day::<day9::solution::Day9>(c);
+    bench_day::<day10::solution::Day10>(c);
+    bench_day::<day11::solution::Day11>(c);
+    bench_day::<day12::solution::Day12>(c);
+    bench_day::<day13::solution::Day13>(c);
+    bench_day::<day14::solution::Day14>(c);
+    bench_day::<day15::solution::Day15>(c);
+    bench_day::<day16::solution::Day16>(c);
+    bench_day::<day17::solution::Day17>(c);
+    bench_day::<day18::solution::Day18>(c);
+    bench_day::<day19::solution::Day19>(c);
+    bench_day::<day20::solution::Day20>(c);
+    bench_day::<day21::solution::Day21>(c);
+    bench_day::<day22::solution::Day22>(c);
+    bench_day::<day23::solution::Day23>(c);
+    bench_day::<day24::solution::Day24>(c);
+    bench_day::<day25::solution::Day25>(c);
+}
+
+criterion_group!(benches, calendar_benchmark);
+criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Writes a #[test] for every fixtures/dayNN/*.txt so a new regression case is just a new file.
+fn main()
+{
+    let fixtures_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("..").join("fixtures");
+    println!("cargo:rerun-if-changed={}", fixtures_dir.display());
+
+    let mut tests = String::new();
+    for (day, input_path) in find_fixture_inputs(&fixtures_dir)
+    {
+        let stem = input_path.file_stem().unwrap().to_string_lossy();
+        let test_name: String = stem.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' }).collect();
+
+        writeln!(tests, "#[test]").unwrap();
+        writeln!(tests, "fn day{day:02}_{test_name}()").unwrap();
+        writeln!(tests, "{{").unwrap();
+        writeln!(tests, "    check({day}, {:?});", input_path.to_string_lossy()).unwrap();
+        writeln!(tests, "}}").unwrap();
+        writeln!(tests).unwrap();
+    }
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("fixture_tests.rs");
+    fs::write(out_path, tests).unwrap();
+}
+
+fn find_fixture_inputs(fixtures_dir: &Path) -> Vec<(u32, PathBuf)>
+{
+    let mut inputs = Vec::new();
+    let Ok(day_dirs) = fs::read_dir(fixtures_dir) else
+    {
+        return inputs;
+    };
+
+    for day_dir in day_dirs.flatten()
+    {
+        let dir_name = day_dir.file_name().to_string_lossy().to_string();
+        let Some(day) = dir_name.strip_prefix("day").and_then(|num| num.parse::<u32>().ok()) else
+        {
+            continue;
+        };
+
+        let Ok(files) = fs::read_dir(day_dir.path()) else
+        {
+            continue;
+        };
+
+        for file in files.flatten()
+        {
+            let path = file.path();
+            if path.extension().is_some_and(|ext| ext == "txt")
+            {
+                inputs.push((day, path));
+            }
+        }
+    }
+
+    inputs.sort();
+    return inputs;
+}
\ No newline at end of file
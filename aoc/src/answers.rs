@@ -0,0 +1,96 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// Lives in the workspace root, next to the day folders.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredAnswer
+{
+    #[serde(rename = "day")]
+    pub m_day: u32,
+    #[serde(rename = "part")]
+    pub m_part: u32,
+    #[serde(rename = "input_hash")]
+    pub m_input_hash: String,
+    #[serde(rename = "answer")]
+    pub m_answer: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict
+{
+    Match,
+    Mismatch(String),
+    Unknown,
+}
+
+// Answers we have already submitted, so a refactored solver can be checked against them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AnswerStore
+{
+    #[serde(rename = "answers", default)]
+    m_answers: Vec<StoredAnswer>,
+}
+
+impl AnswerStore
+{
+    // A missing file is just an empty store.
+    pub fn load(path: &Path) -> Result<Self, String>
+    {
+        match fs::read_to_string(path)
+        {
+            Ok(text) => { return toml::from_str(&text).map_err(|err| format!("couldn't parse {}: {}", path.display(), err.message())); }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => { return Ok(Self::default()); }
+            Err(err) => { return Err(format!("couldn't read {}: {err}", path.display())); }
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String>
+    {
+        let text = toml::to_string(self).map_err(|err| err.to_string())?;
+        return fs::write(path, text).map_err(|err| format!("couldn't write {}: {err}", path.display()));
+    }
+
+    pub fn get(&self, day: u32, part: u32, input_hash: &str) -> Option<&str>
+    {
+        return self.m_answers.iter()
+                    .find(|stored| stored.m_day == day && stored.m_part == part && stored.m_input_hash == input_hash)
+                    .map(|stored| stored.m_answer.as_str());
+    }
+
+    pub fn verify(&self, day: u32, part: u32, input_hash: &str, answer: &str) -> Verdict
+    {
+        match self.get(day, part, input_hash)
+        {
+            Some(stored) if stored == answer => { return Verdict::Match; }
+            Some(stored) => { return Verdict::Mismatch(String::from(stored)); }
+            None => { return Verdict::Unknown; }
+        }
+    }
+
+    // Replaces whatever was stored for the same day, part and input.
+    pub fn record(&mut self, day: u32, part: u32, input_hash: &str, answer: &str)
+    {
+        self.m_answers.retain(|stored| !(stored.m_day == day && stored.m_part == part && stored.m_input_hash == input_hash));
+        self.m_answers.push(StoredAnswer
+        {
+            m_day: day,
+            m_part: part,
+            m_input_hash: String::from(input_hash),
+            m_answer: String::from(answer),
+        });
+        self.m_answers.sort_by(|a, b| (a.m_day, a.m_part, &a.m_input_hash).cmp(&(b.m_day, b.m_part, &b.m_input_hash)));
+    }
+}
+
+// Hex sha256 of the input, line endings are normalised so a checkout with CRLF still matches.
+pub fn hash_input(input: &str) -> String
+{
+    let digest = Sha256::digest(input.replace("\r\n", "\n").as_bytes());
+    return digest.iter().map(|byte| format!("{byte:02x}")).collect();
+}
\ No newline at end of file
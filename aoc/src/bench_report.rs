@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+// Where `cargo bench` leaves its results, relative to the workspace root.
+pub const CRITERION_DIR: &str = "target/criterion";
+
+#[derive(Deserialize)]
+struct Estimate
+{
+    point_estimate: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates
+{
+    median: Estimate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRow
+{
+    pub m_day: u32,
+    pub m_part: u32,
+    // Medians in nanoseconds, None if that step wasn't benchmarked.
+    pub m_parse_ns: Option<f64>,
+    pub m_solve_ns: Option<f64>,
+}
+
+fn read_median(path: &Path) -> Option<f64>
+{
+    let text = fs::read_to_string(path).ok()?;
+    let estimates: Estimates = serde_json::from_str(&text).ok()?;
+    return Some(estimates.median.point_estimate);
+}
+
+// Medians from the latest run of the calendar bench, days that never ran are left out.
+pub fn read_medians(criterion_dir: &Path) -> Vec<BenchRow>
+{
+    let mut rows = Vec::new();
+    for day in 1..=25
+    {
+        for part in 1..=2
+        {
+            let group_dir = criterion_dir.join(format!("day{day:02}_part{part}"));
+            let parse_ns = read_median(&group_dir.join("parse").join("new").join("estimates.json"));
+            let solve_ns = read_median(&group_dir.join("solve").join("new").join("estimates.json"));
+
+            if parse_ns.is_some() || solve_ns.is_some()
+            {
+                rows.push(BenchRow { m_day: day, m_part: part, m_parse_ns: parse_ns, m_solve_ns: solve_ns });
+            }
+        }
+    }
+
+    return rows;
+}
+
+pub fn format_duration(ns: f64) -> String
+{
+    if ns < 1e3
+    {
+        return format!("{ns:.0} ns");
+    }
+    else if ns < 1e6
+    {
+        return format!("{:.1} µs", ns / 1e3);
+    }
+    else if ns < 1e9
+    {
+        return format!("{:.1} ms", ns / 1e6);
+    }
+
+    return format!("{:.2} s", ns / 1e9);
+}
+
+fn format_cell(ns: Option<f64>) -> String
+{
+    return ns.map(format_duration).unwrap_or(String::from("-"));
+}
+
+// Markdown so it can be pasted straight into the README.
+pub fn format_table(rows: &Vec<BenchRow>) -> String
+{
+    let mut table = String::from("| Day | Part | Parse | Solve |\n|----:|-----:|------:|------:|\n");
+    for row in rows.iter()
+    {
+        table += &format!("| {} | {} | {} | {} |\n", row.m_day, row.m_part, format_cell(row.m_parse_ns), format_cell(row.m_solve_ns));
+    }
+
+    let total_parse: f64 = rows.iter().filter_map(|row| row.m_parse_ns).sum();
+    let total_solve: f64 = rows.iter().filter_map(|row| row.m_solve_ns).sum();
+    table += &format!("| Total | | {} | {} |\n", format_duration(total_parse), format_duration(total_solve));
+
+    return table;
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use std::fmt;
+use std::path::Path;
+
+use aoc_core::config::load_params;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::{Answer, PartKind, Solution};
+
+pub enum DayError
+{
+    Config(ParseError),
+    Input(ParseError),
+}
+
+impl fmt::Display for DayError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            DayError::Config(err) => { return write!(f, "couldn't parse config, {err}"); }
+            DayError::Input(err) => { return write!(f, "couldn't parse input, {err}"); }
+        }
+    }
+}
+
+// Takes the input and the config file to read the day's params from.
+pub type PartFn = fn(&String, &Path) -> Result<Answer, DayError>;
+
+pub struct DayEntry
+{
+    pub m_day: u32,
+    pub m_kinds: [PartKind; 2],
+    pub m_parts: [PartFn; 2],
+}
+
+fn run_part<S: Solution>(part: u32, input: &String, config_path: &Path) -> Result<Answer, DayError>
+{
+    let params : S::Params = load_params(config_path).map_err(DayError::Config)?;
+    return S::solve(part, input, &params).map_err(DayError::Input);
+}
+
+fn entry<S: Solution>() -> DayEntry
+{
+    return DayEntry
+    {
+        m_day: S::DAY,
+        m_kinds: S::PART_KINDS,
+        m_parts: [
+            |input, config_path| run_part::<S>(1, input, config_path),
+            |input, config_path| run_part::<S>(2, input, config_path)],
+    };
+}
+
+pub fn all_days() -> Vec<DayEntry>
+{
+    return vec![
+        entry::<day1::solution::Day1>(),
+        entry::<day2::solution::Day2>(),
+        entry::<day3::solution::Day3>(),
+        entry::<day4::solution::Day4>(),
+        entry::<day5::solution::Day5>(),
+        entry::<day6::solution::Day6>(),
+        entry::<day7::solution::Day7>(),
+        entry::<day8::solution::Day8>(),
+        entry::<day9::solution::Day9>(),
+        entry::<day10::solution::Day10>(),
+        entry::<day11::solution::Day11>(),
+        entry::<day12::solution::Day12>(),
+        entry::<day13::solution::Day13>(),
+        entry::<day14::solution::Day14>(),
+        entry::<day15::solution::Day15>(),
+        entry::<day16::solution::Day16>(),
+        entry::<day17::solution::Day17>(),
+        entry::<day18::solution::Day18>(),
+        entry::<day19::solution::Day19>(),
+        entry::<day20::solution::Day20>(),
+        entry::<day21::solution::Day21>(),
+        entry::<day22::solution::Day22>(),
+        entry::<day23::solution::Day23>(),
+        entry::<day24::solution::Day24>(),
+        entry::<day25::solution::Day25>(),
+    ];
+}
+
+pub fn find_day(day: u32) -> Option<DayEntry>
+{
+    return all_days().into_iter().find(|entry| entry.m_day == day);
+}
\ No newline at end of file
@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_core::solution::PartKind;
+
+use crate::days;
+
+// Sidecars sit next to each fixture input, e.g. example.txt has example.expected.toml
+// with its answers and optionally example.config.toml with the params to run it with.
+pub const EXPECTED_EXT: &str = "expected.toml";
+pub const CONFIG_EXT: &str = "config.toml";
+
+pub fn fixtures_dir() -> PathBuf
+{
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join("fixtures");
+}
+
+// Answers from the sidecar, a part that is left out isn't checked.
+fn read_expected(path: &Path) -> Result<[Option<String>; 2], String>
+{
+    let text = fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+    let table: toml::Table = toml::from_str(&text).map_err(|err| format!("couldn't parse {}: {}", path.display(), err.message()))?;
+
+    let mut expected = [None, None];
+    for (key, value) in table
+    {
+        let idx = match key.as_str()
+        {
+            "part1" => 0,
+            "part2" => 1,
+            _ => { return Err(format!("{}: unknown key '{key}', expected part1 or part2", path.display())); }
+        };
+
+        // Numbers can be written bare, anything else like "6,1" as a string.
+        expected[idx] = match value
+        {
+            toml::Value::String(text) => Some(text),
+            other => Some(other.to_string()),
+        };
+    }
+
+    if expected.iter().all(|answer| answer.is_none())
+    {
+        return Err(format!("{} has no answers in it", path.display()));
+    }
+
+    return Ok(expected);
+}
+
+pub fn check_fixture(day: u32, input_path: &Path) -> Result<(), String>
+{
+    let Some(entry) = days::find_day(day) else
+    {
+        return Err(format!("there is no day {day}"));
+    };
+
+    let input = fs::read_to_string(input_path).map_err(|err| format!("couldn't read {}: {err}", input_path.display()))?;
+    let expected = read_expected(&input_path.with_extension(EXPECTED_EXT))?;
+    let config_path = input_path.with_extension(CONFIG_EXT);
+
+    let mut failures: Vec<String> = Vec::new();
+    for idx in 0..2
+    {
+        let Some(expected) = &expected[idx] else
+        {
+            continue;
+        };
+
+        let part = idx + 1;
+        if entry.m_kinds[idx] != PartKind::Solver
+        {
+            failures.push(format!("part {part} has an expected answer but can't be run unattended"));
+            continue;
+        }
+
+        match entry.m_parts[idx](&input, &config_path)
+        {
+            Ok(answer) if answer.to_string() == *expected => {}
+            Ok(answer) => { failures.push(format!("part {part}: expected {expected}, got {answer}")); }
+            Err(err) => { failures.push(format!("part {part}: {err}")); }
+        }
+    }
+
+    if !failures.is_empty()
+    {
+        return Err(format!("{}\n{}", input_path.display(), failures.join("\n")));
+    }
+
+    return Ok(());
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub mod answers;
+pub mod bench_report;
+pub mod days;
+pub mod fixtures;
+pub mod scaffold;
\ No newline at end of file
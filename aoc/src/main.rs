@@ -0,0 +1,356 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use clap::{Parser, Subcommand};
+
+use aoc_core::config::CONFIG_FILE;
+use aoc_core::solution::{Answer, PartKind};
+
+use aoc::answers::{self, AnswerStore, Verdict, ANSWERS_FILE};
+use aoc::bench_report::{self, CRITERION_DIR};
+use aoc::days::{self, DayEntry};
+use aoc::scaffold::{self, NewDay};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Cli
+{
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command
+{
+    /// Run a single day, or the whole calendar with --all
+    Run
+    {
+        /// Day to run (1-25)
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u32>,
+
+        /// Part to run, both parts are run if left out
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+
+        /// Puzzle input file, defaults to dayN/input.txt
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+
+        /// Puzzle params such as grid sizes, defaults to dayN/config.toml if it exists
+        #[arg(long, conflicts_with = "all")]
+        config: Option<PathBuf>,
+
+        /// Run every day, reading each input from dayN/input.txt
+        #[arg(long)]
+        all: bool,
+
+        /// Check each answer against the one recorded for the same input
+        #[arg(long, conflicts_with = "record")]
+        verify: bool,
+
+        /// Store each answer, replacing any recorded for the same input
+        #[arg(long)]
+        record: bool,
+
+        /// Answers store used by --verify and --record
+        #[arg(long, default_value = ANSWERS_FILE)]
+        answers: PathBuf,
+    },
+
+    /// Create dayN from the template and register it with the runner
+    NewDay
+    {
+        /// Day to create
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        day: u32,
+
+        /// Example input from the puzzle text, used for the tests and fixtures
+        #[arg(long)]
+        example: PathBuf,
+
+        /// Expected part 1 answer for the example, a number or a string
+        #[arg(long, allow_hyphen_values = true)]
+        part1: Option<String>,
+
+        /// Expected part 2 answer for the example, a number or a string
+        #[arg(long, allow_hyphen_values = true)]
+        part2: Option<String>,
+    },
+
+    /// Print a table of median parse and solve times from the last `cargo bench -p aoc`
+    Report
+    {
+        /// Criterion output directory
+        #[arg(long, default_value = CRITERION_DIR)]
+        criterion_dir: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum AnswerMode
+{
+    Show,
+    Verify,
+    Record,
+}
+
+struct AnswerLog
+{
+    m_mode: AnswerMode,
+    m_store: AnswerStore,
+    m_mismatches: u32,
+}
+
+impl AnswerLog
+{
+    // Returns a note to print after the answer.
+    fn note(&mut self, day: u32, part: u32, input: &String, answer: &Answer) -> String
+    {
+        let input_hash = answers::hash_input(input);
+        let answer = answer.to_string();
+
+        match self.m_mode
+        {
+            AnswerMode::Show => { return String::new(); }
+            AnswerMode::Record =>
+            {
+                self.m_store.record(day, part, &input_hash, &answer);
+                return String::from(" recorded");
+            }
+            AnswerMode::Verify => {}
+        }
+
+        match self.m_store.verify(day, part, &input_hash, &answer)
+        {
+            Verdict::Match => { return String::from(" ok"); }
+            Verdict::Unknown => { return String::from(" no recorded answer"); }
+            Verdict::Mismatch(expected) =>
+            {
+                self.m_mismatches += 1;
+                return format!(" MISMATCH, recorded {expected}");
+            }
+        }
+    }
+}
+
+fn default_input_path(day: u32) -> PathBuf
+{
+    return PathBuf::from(format!("day{day}/input.txt"));
+}
+
+fn default_config_path(day: u32) -> PathBuf
+{
+    return PathBuf::from(format!("day{day}/{CONFIG_FILE}"));
+}
+
+// Returns false if the input couldn't be parsed.
+fn run_part(entry: &DayEntry, part: u32, input: &String, config_path: &Path, allow_interactive: bool, log: &mut AnswerLog) -> bool
+{
+    let label = format!("Day {} part {}", entry.m_day, part);
+
+    let idx = part as usize - 1;
+    match entry.m_kinds[idx]
+    {
+        PartKind::Solver => {}
+        PartKind::Interactive if allow_interactive => {}
+        PartKind::Interactive =>
+        {
+            println!("{label}: skipped, needs input from the keyboard");
+            return true;
+        }
+        PartKind::Missing =>
+        {
+            println!("{label}: no solution");
+            return true;
+        }
+    }
+
+    let start = Instant::now();
+    let answer = entry.m_parts[idx](input, config_path);
+    let elapsed = start.elapsed();
+
+    match answer
+    {
+        Ok(answer) =>
+        {
+            let note = log.note(entry.m_day, part, input, &answer);
+            println!("{label}: {answer} ({:.2?}){note}", elapsed);
+            return true;
+        }
+        Err(err) =>
+        {
+            println!("{label}: {err}");
+            return false;
+        }
+    }
+}
+
+fn run_day(entry: &DayEntry, part: Option<u32>, input: &String, config_path: &Path, allow_interactive: bool, log: &mut AnswerLog) -> bool
+{
+    match part
+    {
+        Some(part) => { return run_part(entry, part, input, config_path, allow_interactive, log); }
+        None =>
+        {
+            let part1_ok = run_part(entry, 1, input, config_path, allow_interactive, log);
+            let part2_ok = run_part(entry, 2, input, config_path, allow_interactive, log);
+            return part1_ok && part2_ok;
+        }
+    }
+}
+
+fn run_single(day: u32, part: Option<u32>, input: Option<PathBuf>, config: Option<PathBuf>, log: &mut AnswerLog) -> ExitCode
+{
+    let Some(entry) = days::find_day(day) else
+    {
+        eprintln!("There is no day {day}");
+        return ExitCode::FAILURE;
+    };
+
+    let input_path = input.unwrap_or_else(|| default_input_path(day));
+    let contents = match fs::read_to_string(&input_path)
+    {
+        Ok(contents) => contents,
+        Err(err) =>
+        {
+            eprintln!("Couldn't read {}: {err}", input_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let config_path = config.unwrap_or_else(|| default_config_path(day));
+    if !run_day(&entry, part, &contents, &config_path, true, log)
+    {
+        return ExitCode::FAILURE;
+    }
+
+    return ExitCode::SUCCESS;
+}
+
+fn run_all(part: Option<u32>, log: &mut AnswerLog) -> ExitCode
+{
+    let start = Instant::now();
+    let mut failures = 0;
+
+    for entry in days::all_days()
+    {
+        let input_path = default_input_path(entry.m_day);
+        match fs::read_to_string(&input_path)
+        {
+            Ok(contents) =>
+            {
+                if !run_day(&entry, part, &contents, &default_config_path(entry.m_day), false, log)
+                {
+                    failures += 1;
+                }
+            }
+            Err(_) => { println!("Day {}: skipped, no input at {}", entry.m_day, input_path.display()); }
+        }
+    }
+
+    println!("Total: {:.2?}", start.elapsed());
+    if failures > 0
+    {
+        println!("{failures} day(s) failed");
+        return ExitCode::FAILURE;
+    }
+
+    return ExitCode::SUCCESS;
+}
+
+fn main() -> ExitCode
+{
+    let cli = Cli::parse();
+
+    match cli.command
+    {
+        Command::Run { day, part, input, config, all, verify, record, answers } =>
+        {
+            let mode = if verify { AnswerMode::Verify } else if record { AnswerMode::Record } else { AnswerMode::Show };
+            let store = match mode
+            {
+                AnswerMode::Show => AnswerStore::default(),
+                _ => match AnswerStore::load(&answers)
+                {
+                    Ok(store) => store,
+                    Err(err) =>
+                    {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                },
+            };
+
+            let mut log = AnswerLog { m_mode: mode, m_store: store, m_mismatches: 0 };
+            let exit_code = match day
+            {
+                _ if all => run_all(part, &mut log),
+                Some(day) => run_single(day, part, input, config, &mut log),
+                None => unreachable!("clap requires --day without --all"),
+            };
+
+            if mode == AnswerMode::Record
+            {
+                if let Err(err) = log.m_store.save(&answers)
+                {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+
+            if log.m_mismatches > 0
+            {
+                println!("{} answer(s) don't match the recorded ones", log.m_mismatches);
+                return ExitCode::FAILURE;
+            }
+
+            return exit_code;
+        }
+        Command::NewDay { day, example, part1, part2 } =>
+        {
+            let example = match fs::read_to_string(&example)
+            {
+                Ok(example) => example,
+                Err(err) =>
+                {
+                    eprintln!("Couldn't read {}: {err}", example.display());
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let new_day = NewDay { m_day: day, m_example: example, m_part1: part1, m_part2: part2 };
+            match scaffold::scaffold_day(Path::new("."), &new_day)
+            {
+                Ok(created) =>
+                {
+                    for path in created
+                    {
+                        println!("Created {}", path.display());
+                    }
+                    println!("Registered day {day} with the workspace, runner and benches");
+                    return ExitCode::SUCCESS;
+                }
+                Err(err) =>
+                {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Report { criterion_dir } =>
+        {
+            let rows = bench_report::read_medians(&criterion_dir);
+            if rows.is_empty()
+            {
+                eprintln!("No benchmark results in {}, run `cargo bench -p aoc` first", criterion_dir.display());
+                return ExitCode::FAILURE;
+            }
+
+            print!("{}", bench_report::format_table(&rows));
+            return ExitCode::SUCCESS;
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,226 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Copied for every new day, with {{day}}, {{example}}, {{part1}}, {{part2}}, {{part1_type}} and
+// {{part2_type}} filled in.
+pub const TEMPLATE_DIR: &str = "template";
+
+pub struct NewDay
+{
+    pub m_day: u32,
+    pub m_example: String,
+    // Expected answers for the example, a part without one gets its test commented out. Anything
+    // that isn't a number is taken as a string answer.
+    pub m_part1: Option<String>,
+    pub m_part2: Option<String>,
+}
+
+// Lists a day has to be added to before the runner and benches know about it, one line per day.
+const REGISTRATIONS: [(&str, &str); 4] = [
+    ("Cargo.toml", "    \"day{}\","),
+    ("aoc/Cargo.toml", "day{} = { path = \"../day{}\" }"),
+    ("aoc/src/days.rs", "        entry::<day{}::solution::Day{}>(),"),
+    ("aoc/benches/calendar.rs", "    bench_day::<day{}::solution::Day{}>(c);"),
+];
+
+// The example as a raw string literal, with enough #s that nothing in it can end it early.
+fn raw_string_literal(text: &str) -> String
+{
+    let longest_run = text.split('"')
+                          .skip(1)
+                          .map(|after_quote| after_quote.chars().take_while(|c| *c == '#').count())
+                          .max()
+                          .unwrap_or(0);
+    let hashes = "#".repeat(longest_run + 1);
+    return format!("r{hashes}\"{text}\"{hashes}");
+}
+
+fn is_text_answer(answer: &Option<String>) -> bool
+{
+    return answer.as_ref().is_some_and(|answer| answer.parse::<i64>().is_err());
+}
+
+// What compute_answer and solve return, a String for anything that doesn't fit an i64.
+fn answer_type(answer: &Option<String>) -> &'static str
+{
+    return if is_text_answer(answer) { "String" } else { "i64" };
+}
+
+// How the answer is written in the test, matching answer_type.
+fn answer_literal(answer: &Option<String>) -> String
+{
+    match answer
+    {
+        None => { return String::from("0"); }
+        Some(text) if is_text_answer(answer) => { return format!("String::from({text:?})"); }
+        Some(number) => { return number.clone(); }
+    }
+}
+
+fn fill_template(text: &str, new_day: &NewDay) -> String
+{
+    let example = new_day.m_example.replace("\r\n", "\n");
+    return text.replace("{{day}}", &new_day.m_day.to_string())
+               .replace("{{example}}", &raw_string_literal(example.trim_end()))
+               .replace("{{part1}}", &answer_literal(&new_day.m_part1))
+               .replace("{{part2}}", &answer_literal(&new_day.m_part2))
+               .replace("{{part1_type}}", answer_type(&new_day.m_part1))
+               .replace("{{part2_type}}", answer_type(&new_day.m_part2));
+}
+
+// Same as the old hand written template, so the test is there to fill in later.
+fn comment_out_test(tests: &str, part: u32) -> String
+{
+    let mut lines: Vec<String> = tests.split('\n').map(String::from).collect();
+    let Some(fn_idx) = lines.iter().position(|line| line.trim() == format!("fn part_{part}_test()")) else
+    {
+        return String::from(tests);
+    };
+
+    let start = if fn_idx > 0 && lines[fn_idx - 1].trim() == "#[test]" { fn_idx - 1 } else { fn_idx };
+    let end = (fn_idx..lines.len()).find(|idx| lines[*idx].trim_end() == "    }").unwrap_or(lines.len() - 1);
+
+    for line in lines[start..=end].iter_mut()
+    {
+        *line = format!("    // {}", line.strip_prefix("    ").unwrap_or(line));
+    }
+
+    return lines.join("\n");
+}
+
+fn copy_template(from: &Path, to: &Path, new_day: &NewDay, created: &mut Vec<PathBuf>) -> Result<(), String>
+{
+    fs::create_dir_all(to).map_err(|err| format!("couldn't create {}: {err}", to.display()))?;
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(from)
+                                        .map_err(|err| format!("couldn't read {}: {err}", from.display()))?
+                                        .flatten()
+                                        .map(|entry| entry.path())
+                                        .collect();
+    entries.sort();
+
+    for path in entries
+    {
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir()
+        {
+            copy_template(&path, &target, new_day, created)?;
+            continue;
+        }
+
+        let text = fs::read_to_string(&path).map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+        let mut text = fill_template(&text, new_day);
+        if path.file_name().is_some_and(|name| name == "tests.rs")
+        {
+            for (part, answer) in [(1, &new_day.m_part1), (2, &new_day.m_part2)]
+            {
+                if answer.is_none()
+                {
+                    text = comment_out_test(&text, part);
+                }
+            }
+        }
+
+        fs::write(&target, text).map_err(|err| format!("couldn't write {}: {err}", target.display()))?;
+        created.push(target);
+    }
+
+    return Ok(());
+}
+
+// The day a line registers, if it is one of the lines made from `pattern`.
+fn registered_day(line: &str, pattern: &str) -> Option<u32>
+{
+    let (prefix, _) = pattern.split_once("{}").unwrap();
+    let rest = line.strip_prefix(prefix)?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let day = digits.parse::<u32>().ok()?;
+
+    if line != pattern.replace("{}", &day.to_string())
+    {
+        return None;
+    }
+
+    return Some(day);
+}
+
+// Inserts the day's line so the list stays in day order.
+fn register_day(path: &Path, pattern: &str, day: u32) -> Result<(), String>
+{
+    let text = fs::read_to_string(path).map_err(|err| format!("couldn't read {}: {err}", path.display()))?;
+    let line_ending = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let mut lines: Vec<&str> = text.split(line_ending).collect();
+    let new_line = pattern.replace("{}", &day.to_string());
+
+    let registered: Vec<(usize, u32)> = lines.iter()
+                                             .enumerate()
+                                             .filter_map(|(idx, line)| registered_day(line, pattern).map(|d| (idx, d)))
+                                             .collect();
+
+    if registered.iter().any(|(_, d)| *d == day)
+    {
+        return Ok(());
+    }
+
+    let insert_at = match registered.iter().rfind(|(_, d)| *d < day)
+    {
+        Some((idx, _)) => idx + 1,
+        None => match registered.first()
+        {
+            Some((idx, _)) => *idx,
+            None => { return Err(format!("couldn't find the list of days in {}", path.display())); }
+        },
+    };
+
+    lines.insert(insert_at, &new_line);
+    return fs::write(path, lines.join(line_ending)).map_err(|err| format!("couldn't write {}: {err}", path.display()));
+}
+
+// Creates dayN from the template, adds the example to the fixtures and registers the crate with
+// the workspace, runner and benches. Returns the files that were created.
+pub fn scaffold_day(root: &Path, new_day: &NewDay) -> Result<Vec<PathBuf>, String>
+{
+    let day = new_day.m_day;
+    let day_dir = root.join(format!("day{day}"));
+    if day_dir.exists()
+    {
+        return Err(format!("{} already exists", day_dir.display()));
+    }
+
+    let mut created = Vec::new();
+    copy_template(&root.join(TEMPLATE_DIR), &day_dir, new_day, &mut created)?;
+
+    let mut expected = String::new();
+    for (part, answer) in [(1, &new_day.m_part1), (2, &new_day.m_part2)]
+    {
+        match answer.as_ref().map(|answer| (answer, answer.parse::<i64>()))
+        {
+            Some((_, Ok(number))) => { expected += &format!("part{part} = {number}\n"); }
+            Some((answer, Err(_))) => { expected += &format!("part{part} = {}\n", toml::Value::from(answer.as_str())); }
+            None => {}
+        }
+    }
+
+    // The harness wants at least one answer per fixture.
+    if !expected.is_empty()
+    {
+        let fixture_dir = root.join("fixtures").join(format!("day{day:02}"));
+        fs::create_dir_all(&fixture_dir).map_err(|err| format!("couldn't create {}: {err}", fixture_dir.display()))?;
+
+        let example_path = fixture_dir.join("example.txt");
+        let expected_path = fixture_dir.join("example.expected.toml");
+        let example = format!("{}\n", new_day.m_example.replace("\r\n", "\n").trim_end());
+
+        fs::write(&example_path, example).map_err(|err| format!("couldn't write {}: {err}", example_path.display()))?;
+        fs::write(&expected_path, expected).map_err(|err| format!("couldn't write {}: {err}", expected_path.display()))?;
+        created.push(example_path);
+        created.push(expected_path);
+    }
+
+    for (file, pattern) in REGISTRATIONS
+    {
+        register_day(&root.join(file), pattern, day)?;
+    }
+
+    return Ok(created);
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::env;
+use std::fs;
+
+use aoc::answers::{hash_input, AnswerStore, Verdict};
+
+#[test]
+fn missing_file_is_empty_store()
+{
+    let path = env::temp_dir().join(format!("aoc_answers_missing_{}.toml", std::process::id()));
+    let store = AnswerStore::load(&path).unwrap();
+
+    assert_eq!(store.get(1, 1, "abc"), None);
+}
+
+#[test]
+fn record_replaces_and_round_trips()
+{
+    let mut store = AnswerStore::default();
+    store.record(3, 1, "abc", "161");
+    store.record(3, 2, "abc", "48");
+    store.record(3, 1, "abc", "162");
+
+    assert_eq!(store.get(3, 1, "abc"), Some("162"));
+    assert_eq!(store.get(3, 2, "abc"), Some("48"));
+    assert_eq!(store.get(3, 1, "def"), None);
+
+    let path = env::temp_dir().join(format!("aoc_answers_saved_{}.toml", std::process::id()));
+    store.save(&path).unwrap();
+    let text = fs::read_to_string(&path).unwrap();
+    let loaded = AnswerStore::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(text.matches("[[answers]]").count(), 2);
+    assert_eq!(loaded.unwrap().get(3, 1, "abc"), Some("162"));
+}
+
+#[test]
+fn verify_verdicts()
+{
+    let mut store = AnswerStore::default();
+    store.record(7, 2, "abc", "11387");
+
+    assert_eq!(store.verify(7, 2, "abc", "11387"), Verdict::Match);
+    assert_eq!(store.verify(7, 2, "abc", "3749"), Verdict::Mismatch(String::from("11387")));
+    assert_eq!(store.verify(7, 1, "abc", "3749"), Verdict::Unknown);
+    assert_eq!(store.verify(7, 2, "def", "11387"), Verdict::Unknown);
+}
+
+#[test]
+fn hash_ignores_line_endings()
+{
+    assert_eq!(hash_input("1 2\n3 4\n"), hash_input("1 2\r\n3 4\r\n"));
+    assert_ne!(hash_input("1 2\n3 4\n"), hash_input("1 2\n3 5\n"));
+    assert_eq!(hash_input("").len(), 64);
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use std::env;
+use std::fs;
+
+use aoc::bench_report::{format_table, read_medians, BenchRow};
+
+#[test]
+fn reads_criterion_medians()
+{
+    let criterion_dir = env::temp_dir().join(format!("aoc_bench_report_{}", std::process::id()));
+    for (bench, median) in [("day03_part1/parse", 1500.0), ("day03_part1/solve", 2500000.0), ("day03_part2/parse", 900.0)]
+    {
+        let bench_dir = criterion_dir.join(bench).join("new");
+        fs::create_dir_all(&bench_dir).unwrap();
+        fs::write(bench_dir.join("estimates.json"), format!("{{\"mean\":{{\"point_estimate\":1.0}},\"median\":{{\"point_estimate\":{median}}}}}")).unwrap();
+    }
+
+    let rows = read_medians(&criterion_dir);
+    fs::remove_dir_all(&criterion_dir).unwrap();
+
+    assert_eq!(rows, vec![
+        BenchRow { m_day: 3, m_part: 1, m_parse_ns: Some(1500.0), m_solve_ns: Some(2500000.0) },
+        BenchRow { m_day: 3, m_part: 2, m_parse_ns: Some(900.0), m_solve_ns: None },
+    ]);
+
+    let table = format_table(&rows);
+    assert!(table.contains("| 3 | 1 | 1.5 µs | 2.5 ms |"));
+    assert!(table.contains("| 3 | 2 | 900 ns | - |"));
+    assert!(table.contains("| Total | | 2.4 µs | 2.5 ms |"));
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use std::path::Path;
+
+use aoc::fixtures::check_fixture;
+
+fn check(day: u32, input_path: &str)
+{
+    if let Err(err) = check_fixture(day, Path::new(input_path))
+    {
+        panic!("{err}");
+    }
+}
+
+// One test per fixtures/dayNN/*.txt, generated by build.rs.
+include!(concat!(env!("OUT_DIR"), "/fixture_tests.rs"));
\ No newline at end of file
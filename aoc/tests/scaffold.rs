@@ -0,0 +1,101 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc::scaffold::{scaffold_day, NewDay, TEMPLATE_DIR};
+
+fn copy_dir(from: &Path, to: &Path)
+{
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap().flatten()
+    {
+        let target = to.join(entry.file_name());
+        if entry.path().is_dir()
+        {
+            copy_dir(&entry.path(), &target);
+        }
+        else
+        {
+            fs::copy(entry.path(), target).unwrap();
+        }
+    }
+}
+
+// Template plus just enough of a workspace for the registrations to find their lists.
+fn fake_workspace(name: &str) -> PathBuf
+{
+    let root = env::temp_dir().join(format!("aoc_scaffold_{name}_{}", std::process::id()));
+    copy_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(TEMPLATE_DIR), &root.join(TEMPLATE_DIR));
+    fs::create_dir_all(root.join("aoc/src")).unwrap();
+    fs::create_dir_all(root.join("aoc/benches")).unwrap();
+    fs::write(root.join("Cargo.toml"), "members = [\n    \"aoc\",\n    \"day1\",\n    \"day3\",\n]\n").unwrap();
+    fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\n").unwrap();
+    fs::write(root.join("aoc/src/days.rs"), "    return vec![\r\n        entry::<day1::solution::Day1>(),\r\n        entry::<day3::solution::Day3>(),\r\n    ];").unwrap();
+    fs::write(root.join("aoc/benches/calendar.rs"), "    bench_day::<day1::solution::Day1>(c);\n    bench_day::<day3::solution::Day3>(c);\n").unwrap();
+    return root;
+}
+
+#[test]
+fn scaffolds_and_registers_day()
+{
+    let root = fake_workspace("numbers");
+    let new_day = NewDay { m_day: 2, m_example: String::from("7 6 4\r\n1 2 7\r\n"), m_part1: Some(String::from("2")), m_part2: None };
+    let result = scaffold_day(&root, &new_day);
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+    let outcome = result.map(|_| (read("Cargo.toml"), read("aoc/src/days.rs"), read("day2/Cargo.toml"), read("day2/src/tests.rs"), read("fixtures/day02/example.expected.toml")));
+    let again = scaffold_day(&root, &new_day);
+    fs::remove_dir_all(&root).unwrap();
+
+    let (workspace, days, manifest, tests, expected) = outcome.unwrap();
+    assert!(workspace.contains("    \"day1\",\n    \"day2\",\n    \"day3\","));
+    assert!(days.contains("Day1>(),\r\n        entry::<day2::solution::Day2>(),\r\n"));
+    assert!(manifest.contains("name = \"day2\""));
+    assert!(tests.contains("r#\"7 6 4\n1 2 7\"#"));
+    assert!(tests.contains("assert_eq!(result, Ok(2));"));
+    assert!(tests.contains("    // fn part_2_test()"));
+    assert_eq!(expected, "part1 = 2\n");
+    assert!(again.is_err());
+}
+
+#[test]
+fn scaffolds_string_answers_and_hashes()
+{
+    let root = fake_workspace("strings");
+    let new_day = NewDay { m_day: 4, m_example: String::from("say \"#hi\"##\n"), m_part1: Some(String::from("-3")), m_part2: Some(String::from("co,de")) };
+    let result = scaffold_day(&root, &new_day);
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+    let outcome = result.map(|_| (read("day4/src/tests.rs"), read("day4/src/part1.rs"), read("day4/src/part2.rs"), read("fixtures/day04/example.expected.toml")));
+    fs::remove_dir_all(&root).unwrap();
+
+    let (tests, part1, part2, expected) = outcome.unwrap();
+    assert!(tests.contains("r###\"say \"#hi\"##\"###"));
+    assert!(tests.contains("assert_eq!(result, Ok(-3));"));
+    assert!(tests.contains("assert_eq!(result, Ok(String::from(\"co,de\")));"));
+
+    // The test only compiles if compute_answer returns the same type as the literal.
+    assert!(part1.contains("pub fn compute_answer(input: &String) -> Result<i64, ParseError>"));
+    assert!(part1.contains("pub fn solve(lines: &Vec<String>) -> i64"));
+    assert!(part2.contains("pub fn compute_answer(input: &String) -> Result<String, ParseError>"));
+    assert!(part2.contains("pub fn solve(lines: &Vec<String>) -> String"));
+    assert!(!part1.contains("{{") && !part2.contains("{{"));
+    assert_eq!(expected, "part1 = -3\npart2 = \"co,de\"\n");
+}
+
+#[test]
+fn scaffolds_answers_too_big_for_i64_as_strings()
+{
+    let root = fake_workspace("big");
+    let new_day = NewDay { m_day: 5, m_example: String::from("1\n"), m_part1: Some(String::from("99999999999999999999")), m_part2: None };
+    let result = scaffold_day(&root, &new_day);
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+    let outcome = result.map(|_| (read("day5/src/tests.rs"), read("day5/src/part1.rs"), read("day5/src/part2.rs")));
+    fs::remove_dir_all(&root).unwrap();
+
+    let (tests, part1, part2) = outcome.unwrap();
+    assert!(tests.contains("assert_eq!(result, Ok(String::from(\"99999999999999999999\")));"));
+    assert!(part1.contains("-> Result<String, ParseError>"));
+    assert!(part2.contains("-> Result<i64, ParseError>"));
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+use glam::IVec2;
 use num::{Integer, Signed};
 
 pub fn positive_mod<T>(a: T, b: T) -> T
@@ -11,6 +12,12 @@ where
     return r;
 }
 
+// positive_mod on both axes, so `pos` lands inside a `size` rectangle that wraps around.
+pub fn wrap_vec(pos: IVec2, size: IVec2) -> IVec2
+{
+    return IVec2::new(positive_mod(pos.x, size.x), positive_mod(pos.y, size.y));
+}
+
 
 pub fn get_str_nums(input: &str) -> Vec<Vec<i32>>
 {
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use glam::IVec2;
+
+use crate::grid::Grid;
+use crate::parse_error::ParseError;
+
+pub type CharGrid = Grid<char>;
+
+impl Grid<char>
+{
+    // Every character is a valid cell, so this only fails on ragged rows.
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    {
+        return Self::from_parser(input, Ok);
+    }
+
+    // For input that is known to be rectangular.
+    pub fn from(input : &str) -> Self 
+    {
+        return Self::parse(input).unwrap_or_else(|err| panic!("Not a rectangular grid, {err}"));
+    }
+
+    pub fn from_char(all_char: char, width: i32, height: i32) -> Self 
+    {
+        return Self::from_value(all_char, width, height);
+    }
+
+    // Where each char appears, each list in reading order.
+    pub fn positions_by_char(&self) -> HashMap<char, Vec<IVec2>>
+    {
+        let mut result: HashMap<char, Vec<IVec2>> = HashMap::new();
+        for (pos, c) in self.cells()
+        {
+            result.entry(*c).or_default().push(pos);
+        }
+
+        return result;
+    }
+}
+
+impl fmt::Display for Grid<char>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let mut chars: Vec<char> = Vec::new();
+
+        for y in 0..self.m_height
+        {
+            for x in 0..self.m_width
+            {
+                chars.push(self[IVec2::new(x, y)]);
+            }
+
+            chars.push('\n');
+        }
+
+        return write!(f, "{}", chars.into_iter().collect::<String>());
+    }
+}
\ No newline at end of file
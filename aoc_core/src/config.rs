@@ -0,0 +1,45 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::parse_error::ParseError;
+
+// Each day looks for this next to its input.
+pub const CONFIG_FILE: &str = "config.toml";
+
+// Params for days that don't need anything besides their input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+// Keys left out of the file keep the value from P::default().
+pub fn params_from_toml<P: DeserializeOwned>(text: &str) -> Result<P, ParseError>
+{
+    match toml::from_str(text)
+    {
+        Ok(params) => { return Ok(params); }
+        Err(err) =>
+        {
+            let reason = err.message().trim_end().to_string();
+            match err.span()
+            {
+                Some(span) => { return Err(ParseError::at_offset(text, span.start, reason)); }
+                None => { return Err(ParseError::new(1, 1, reason)); }
+            }
+        }
+    }
+}
+
+// A missing file isn't an error, the defaults are the real puzzle's values.
+pub fn load_params<P: DeserializeOwned + Default>(path: &Path) -> Result<P, ParseError>
+{
+    match fs::read_to_string(path)
+    {
+        Ok(text) => { return params_from_toml(&text); }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => { return Ok(P::default()); }
+        Err(err) => { return Err(ParseError::new(1, 1, format!("couldn't read {}: {err}", path.display()))); }
+    }
+}
\ No newline at end of file
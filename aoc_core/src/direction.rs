@@ -0,0 +1,148 @@
+use std::fmt;
+
+use glam::IVec2;
+
+// Clockwise from North. North is up, so y grows going South.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction
+{
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+impl Direction
+{
+    pub const CARDINALS: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::North, Direction::NorthEast, Direction::East, Direction::SouthEast,
+        Direction::South, Direction::SouthWest, Direction::West, Direction::NorthWest];
+
+    // Up, right, down, left.
+    pub fn iter_4() -> impl Iterator<Item = Direction>
+    {
+        return Self::CARDINALS.into_iter();
+    }
+
+    // Clockwise from North, diagonals included.
+    pub fn iter_8() -> impl Iterator<Item = Direction>
+    {
+        return Self::ALL.into_iter();
+    }
+
+    // Arrows as drawn in the puzzles.
+    pub fn from(chararacter : char) -> Option<Self>
+    {
+        match chararacter
+        {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            '<' => Some(Direction::West),
+            'v'|'V' => Some(Direction::South),
+            _ => None
+        }
+    }
+
+    // "N", "NE", "E" and so on, either case.
+    pub fn from_compass(text: &str) -> Option<Self>
+    {
+        let text = text.to_ascii_uppercase();
+        return Self::ALL.into_iter().find(|dir| dir.compass() == text);
+    }
+
+    pub fn compass(&self) -> &'static str
+    {
+        match self
+        {
+            Direction::North => "N",
+            Direction::NorthEast => "NE",
+            Direction::East => "E",
+            Direction::SouthEast => "SE",
+            Direction::South => "S",
+            Direction::SouthWest => "SW",
+            Direction::West => "W",
+            Direction::NorthWest => "NW"
+        }
+    }
+
+    // One step this way.
+    pub fn to_vec(&self) -> IVec2
+    {
+        match self
+        {
+            Direction::North => IVec2::new(0, -1),
+            Direction::NorthEast => IVec2::new(1, -1),
+            Direction::East => IVec2::new(1, 0),
+            Direction::SouthEast => IVec2::new(1, 1),
+            Direction::South => IVec2::new(0, 1),
+            Direction::SouthWest => IVec2::new(-1, 1),
+            Direction::West => IVec2::new(-1, 0),
+            Direction::NorthWest => IVec2::new(-1, -1)
+        }
+    }
+
+    // Only single steps have a direction.
+    pub fn from_vec(delta: IVec2) -> Option<Self>
+    {
+        return Self::ALL.into_iter().find(|dir| dir.to_vec() == delta);
+    }
+
+    pub fn is_diagonal(&self) -> bool
+    {
+        return !Self::CARDINALS.contains(self);
+    }
+
+    pub fn add_to(&self, pos: IVec2) -> IVec2
+    {
+        return pos + self.to_vec();
+    }
+
+    // Eighths of a turn clockwise, negative for anticlockwise.
+    pub fn rotate(&self, eighths: i32) -> Direction
+    {
+        let idx = Self::ALL.iter().position(|dir| dir == self).unwrap() as i32;
+        return Self::ALL[(idx + eighths).rem_euclid(8) as usize];
+    }
+
+    // 180 degrees.
+    pub fn invert(&self) -> Direction
+    {
+        return self.rotate(4);
+    }
+
+    // 90 degrees clockwise.
+    pub fn rot_right(&self) -> Direction
+    {
+        return self.rotate(2);
+    }
+
+    pub fn rot_left(&self) -> Direction
+    {
+        return self.rotate(-2);
+    }
+
+    // 45 degrees clockwise.
+    pub fn rot_right_45(&self) -> Direction
+    {
+        return self.rotate(1);
+    }
+
+    pub fn rot_left_45(&self) -> Direction
+    {
+        return self.rotate(-1);
+    }
+}
+
+impl fmt::Display for Direction
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        return write!(f, "{}", self.compass());
+    }
+}
\ No newline at end of file
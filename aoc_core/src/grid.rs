@@ -0,0 +1,391 @@
+use std::ops::{Index, IndexMut};
+
+use glam::IVec2;
+
+use crate::parse_error::*;
+use crate::search::{bfs, GridWalk};
+
+// Up, right, down, left.
+pub(crate) const NEIGHBOURS_4: [IVec2; 4] = [IVec2::NEG_Y, IVec2::X, IVec2::Y, IVec2::NEG_X];
+
+// Clockwise from up.
+pub(crate) const NEIGHBOURS_8: [IVec2; 8] = [
+    IVec2::new(0, -1), IVec2::new(1, -1), IVec2::new(1, 0), IVec2::new(1, 1),
+    IVec2::new(0, 1), IVec2::new(-1, 1), IVec2::new(-1, 0), IVec2::new(-1, -1)];
+
+// Row-major rectangle of cells. CharGrid and IntGrid are this with char and i32 cells.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T>
+{
+    m_cells: Vec<T>,
+    pub m_width: i32,
+    pub m_height: i32,
+}
+
+impl<T> Grid<T>
+{
+    // One cell per character, `parse_cell` gives the reason a character isn't valid. Line endings
+    // can be LF or CRLF, trailing whitespace and blank lines at the end are ignored, and every row
+    // has to be as wide as the first.
+    pub fn from_parser(input: &str, mut parse_cell: impl FnMut(char) -> Result<T, String>) -> Result<Self, ParseError>
+    {
+        let rows = grid_rows(input);
+
+        let width = rows.first().map_or(0, |(_, row)| row.chars().count());
+        let height = rows.len();
+
+        let mut cells: Vec<T> = Vec::with_capacity(width * height);
+        for (line_num, row) in rows
+        {
+            let row_width = row.chars().count();
+            if row_width != width
+            {
+                return Err(ParseError::new(line_num, row_width.min(width) + 1, format!("row is {row_width} wide but the first row is {width} wide")));
+            }
+
+            for (idx, c) in row.chars().enumerate()
+            {
+                let cell = parse_cell(c).map_err(|reason| ParseError::new(line_num, idx + 1, reason))?;
+                cells.push(cell);
+            }
+        }
+
+        let this = Self
+        {
+            m_cells: cells,
+            m_width: width as i32,
+            m_height: height as i32,
+        };
+
+        return Ok(this);
+    }
+
+    // Like from_parser, but cells are tokens separated by whitespace or commas so they can be
+    // more than one character long.
+    pub fn from_tokens(input: &str, mut parse_token: impl FnMut(&str) -> Result<T, String>) -> Result<Self, ParseError>
+    {
+        let rows: Vec<(usize, &str, Vec<&str>)> = grid_rows(input).into_iter()
+            .map(|(line_num, row)| (line_num, row, row.split(|c: char| c == ',' || c.is_whitespace()).filter(|token| !token.is_empty()).collect()))
+            .collect();
+
+        let width = rows.first().map_or(0, |(_, _, tokens)| tokens.len());
+        let height = rows.len();
+
+        let mut cells: Vec<T> = Vec::with_capacity(width * height);
+        for (line_num, row, tokens) in rows
+        {
+            if tokens.len() != width
+            {
+                let column = tokens.get(width).map_or(row.chars().count() + 1, |token| column_of(row, token));
+                return Err(ParseError::new(line_num, column, format!("row has {} cells but the first row has {width}", tokens.len())));
+            }
+
+            for token in tokens
+            {
+                let cell = parse_token(token).map_err(|reason| ParseError::at_token(line_num, row, token, reason))?;
+                cells.push(cell);
+            }
+        }
+
+        let this = Self
+        {
+            m_cells: cells,
+            m_width: width as i32,
+            m_height: height as i32,
+        };
+
+        return Ok(this);
+    }
+
+    pub fn from_value(value: T, width: i32, height: i32) -> Self
+        where T: Clone
+    {
+        let this = Self
+        {
+            m_cells: vec![value; (width * height) as usize],
+            m_width: width,
+            m_height: height,
+        };
+
+        return this;
+    }
+
+    // `make_cell` is called for every position, row by row.
+    pub fn from_fn(width: i32, height: i32, make_cell: impl FnMut(IVec2) -> T) -> Self
+    {
+        let cells: Vec<T> = (0..height).flat_map(|y| (0..width).map(move |x| IVec2::new(x, y))).map(make_cell).collect();
+
+        let this = Self
+        {
+            m_cells: cells,
+            m_width: width,
+            m_height: height,
+        };
+
+        return this;
+    }
+
+    pub fn inside_grid(&self, x: i32, y: i32) -> bool
+    {
+        return (0..self.m_width).contains(&x) && (0..self.m_height).contains(&y);
+    }
+
+    pub fn inside_grid_vec(&self, pos: IVec2) -> bool
+    {
+        return self.inside_grid(pos.x, pos.y);
+    }
+
+    fn index_of(&self, x: i32, y: i32) -> Option<usize>
+    {
+        if !self.inside_grid(x, y)
+        {
+            return None;
+        }
+
+        return Some((x + y * self.m_width) as usize);
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T>
+    {
+        return self.index_of(pos.x, pos.y).map(|idx| &self.m_cells[idx]);
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T>
+    {
+        return self.index_of(pos.x, pos.y).map(|idx| &mut self.m_cells[idx]);
+    }
+
+    pub fn at(&self, x: i32, y: i32) -> Option<T>
+        where T: Copy
+    {
+        return self.index_of(x, y).map(|idx| self.m_cells[idx]);
+    }
+
+    pub fn at_vec(&self, pos: IVec2) -> Option<T>
+        where T: Copy
+    {
+        return self.at(pos.x, pos.y);
+    }
+
+    // False if the position is off the grid.
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> bool
+    {
+        let Some(idx) = self.index_of(x, y) else
+        {
+            return false;
+        };
+
+        self.m_cells[idx] = value;
+        return true;
+    }
+
+    pub fn set_v(&mut self, pos: IVec2, value: T) -> bool
+    {
+        return self.set(pos.x, pos.y, value);
+    }
+
+    // Row-major position of `pos` in the cells, for hot loops that step by index instead of by
+    // position. Only checked in debug builds.
+    pub fn flat_index(&self, pos: IVec2) -> usize
+    {
+        debug_assert!(self.inside_grid_vec(pos), "{pos} is outside the {}x{} grid", self.m_width, self.m_height);
+        return (pos.x + pos.y * self.m_width) as usize;
+    }
+
+    pub fn position_of(&self, index: usize) -> IVec2
+    {
+        return IVec2::new(index as i32 % self.m_width, index as i32 / self.m_width);
+    }
+
+    // Index offsets for up, right, down and left, the same order as neighbours_4.
+    pub fn flat_offsets_4(&self) -> [isize; 4]
+    {
+        let width = self.m_width as isize;
+        return [-width, 1, width, -1];
+    }
+
+    // Every cell, row by row.
+    pub fn as_slice(&self) -> &[T]
+    {
+        return &self.m_cells;
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T]
+    {
+        return &mut self.m_cells;
+    }
+
+    pub fn into_vec(self) -> Vec<T>
+    {
+        return self.m_cells;
+    }
+
+    // A copy with `padding` cells of `border` all the way round, so everything moves by
+    // (padding, padding). Lookups next to the original edge can't go out of bounds.
+    pub fn padded(&self, border: T, padding: i32) -> Self
+        where T: Clone
+    {
+        let offset = IVec2::splat(padding);
+        return Self::from_fn(self.m_width + 2 * padding, self.m_height + 2 * padding, |pos| self.get(pos - offset).unwrap_or(&border).clone());
+    }
+
+    // Same shape, every cell converted.
+    pub fn map<U>(&self, convert: impl FnMut(&T) -> U) -> Grid<U>
+    {
+        return Grid
+        {
+            m_cells: self.m_cells.iter().map(convert).collect(),
+            m_width: self.m_width,
+            m_height: self.m_height,
+        };
+    }
+
+    // Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2>
+    {
+        let width = self.m_width;
+        return (0..self.m_height).flat_map(move |y| (0..width).map(move |x| IVec2::new(x, y)));
+    }
+
+    // Every cell with its position, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (IVec2, &T)> + '_
+    {
+        return self.positions().zip(self.m_cells.iter());
+    }
+
+    pub fn positions_where<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = IVec2> + 'a
+    {
+        return self.cells().filter(move |(_, cell)| predicate(cell)).map(|(pos, _)| pos);
+    }
+
+    pub fn row(&self, y: i32) -> &[T]
+    {
+        assert!((0..self.m_height).contains(&y), "row {y} is outside the {}x{} grid", self.m_width, self.m_height);
+
+        let start = (y * self.m_width) as usize;
+        return &self.m_cells[start..start + self.m_width as usize];
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]>
+    {
+        return (0..self.m_height).map(|y| self.row(y));
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T>
+    {
+        assert!((0..self.m_width).contains(&x), "column {x} is outside the {}x{} grid", self.m_width, self.m_height);
+
+        return (0..self.m_height).map(move |y| &self[IVec2::new(x, y)]);
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>>
+    {
+        return (0..self.m_width).map(|x| self.column(x));
+    }
+
+    // Up, right, down and left of `pos`, leaving out any off the edge.
+    pub fn neighbours_4(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_
+    {
+        return NEIGHBOURS_4.into_iter().map(move |offset| pos + offset).filter(|next| self.inside_grid_vec(*next));
+    }
+
+    // Same as neighbours_4 plus the diagonals.
+    pub fn neighbours_8(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_
+    {
+        return NEIGHBOURS_8.into_iter().map(move |offset| pos + offset).filter(|next| self.inside_grid_vec(*next));
+    }
+
+    // Fewest 4-way steps from `start` to every cell, only walking on cells that are `passable`.
+    // None where it can't be reached.
+    pub fn bfs_distances(&self, start: IVec2, passable: impl Fn(&T) -> bool) -> Grid<Option<u32>>
+    {
+        let reached = bfs(&GridWalk::from(self, passable), start, |_| false);
+        return Grid::from_fn(self.m_width, self.m_height, |pos| reached.cost(pos).map(|cost| cost as u32));
+    }
+
+    // First match in reading order.
+    pub fn find_first(&self, item: T) -> Option<IVec2>
+        where T: PartialEq
+    {
+        return self.positions_where(|cell| *cell == item).next();
+    }
+
+    // Every match in reading order.
+    pub fn find_all(&self, item: T) -> Vec<IVec2>
+        where T: PartialEq
+    {
+        return self.positions_where(|cell| *cell == item).collect();
+    }
+
+    pub fn count(&self, item: T) -> usize
+        where T: PartialEq
+    {
+        return self.m_cells.iter().filter(|cell| **cell == item).count();
+    }
+
+    // For markers that must appear exactly once, like a start tile. `what` names it in the error.
+    pub fn expect_unique(&self, item: T, what: &str) -> Result<IVec2, ParseError>
+        where T: PartialEq
+    {
+        return self.expect_unique_where(what, |cell| *cell == item);
+    }
+
+    pub fn expect_unique_where(&self, what: &str, predicate: impl Fn(&T) -> bool) -> Result<IVec2, ParseError>
+    {
+        let mut matches = self.positions_where(predicate);
+
+        let Some(first) = matches.next() else
+        {
+            return Err(ParseError::new(1, 1, format!("no {what} in the grid")));
+        };
+
+        // Point at the duplicate, the first one is probably the intended one.
+        if let Some(second) = matches.next()
+        {
+            return Err(ParseError::new(second.y as usize + 1, second.x as usize + 1, format!("found a second {what}, the first is at {}:{}", first.y + 1, first.x + 1)));
+        }
+
+        return Ok(first);
+    }
+}
+
+// Panics outside the grid, use get() when the position might be off the edge.
+impl<T> Index<IVec2> for Grid<T>
+{
+    type Output = T;
+
+    fn index(&self, pos: IVec2) -> &T
+    {
+        let Some(idx) = self.index_of(pos.x, pos.y) else
+        {
+            panic!("{pos} is outside the {}x{} grid", self.m_width, self.m_height);
+        };
+
+        return &self.m_cells[idx];
+    }
+}
+
+impl<T> IndexMut<IVec2> for Grid<T>
+{
+    fn index_mut(&mut self, pos: IVec2) -> &mut T
+    {
+        let Some(idx) = self.index_of(pos.x, pos.y) else
+        {
+            panic!("{pos} is outside the {}x{} grid", self.m_width, self.m_height);
+        };
+
+        return &mut self.m_cells[idx];
+    }
+}
+
+// Lines with trailing whitespace trimmed, leaving out blank lines at the end.
+pub(crate) fn grid_rows(input: &str) -> Vec<(usize, &str)>
+{
+    let mut rows: Vec<(usize, &str)> = numbered_lines(input).map(|(line_num, line)| (line_num, line.trim_end())).collect();
+    while rows.last().is_some_and(|(_, row)| row.is_empty())
+    {
+        rows.pop();
+    }
+
+    return rows;
+}
\ No newline at end of file
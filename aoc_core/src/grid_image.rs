@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use crate::char_grid::CharGrid;
+use crate::grid::Grid;
+
+pub type Rgb = [u8; 3];
+
+// Colours for drawing a CharGrid, any char that isn't listed gets the fallback.
+#[derive(Clone, Debug)]
+pub struct Palette
+{
+    m_colours: HashMap<char, Rgb>,
+    m_fallback: Rgb,
+}
+
+impl Palette
+{
+    pub fn from(colours: &[(char, Rgb)], fallback: Rgb) -> Self
+    {
+        return Self
+        {
+            m_colours: colours.iter().copied().collect(),
+            m_fallback: fallback,
+        };
+    }
+
+    pub fn set(&mut self, c: char, colour: Rgb)
+    {
+        self.m_colours.insert(c, colour);
+    }
+
+    pub fn colour_of(&self, c: char) -> Rgb
+    {
+        return self.m_colours.get(&c).copied().unwrap_or(self.m_fallback);
+    }
+}
+
+// Dark floor and walls, with the usual puzzle markers picked out.
+impl Default for Palette
+{
+    fn default() -> Self
+    {
+        return Self::from(&[
+            ('.', [16, 16, 24]),
+            ('#', [96, 96, 104]),
+            ('S', [64, 200, 64]),
+            ('E', [220, 60, 60]),
+            ('@', [220, 80, 220]), ('^', [220, 80, 220]), ('>', [220, 80, 220]), ('v', [220, 80, 220]), ('<', [220, 80, 220]),
+            ('O', [230, 200, 60]), ('[', [230, 200, 60]), (']', [230, 200, 60])], [240, 240, 240]);
+    }
+}
+
+// Each cell becomes a `scale` x `scale` square. Written as PNG if the path ends in .png, PPM otherwise.
+pub fn write_image<T>(grid: &Grid<T>, path: &Path, scale: u32, colour_of: impl Fn(&T) -> Rgb) -> Result<(), String>
+{
+    let scale = scale.max(1) as usize;
+    let width = grid.m_width as usize * scale;
+    let height = grid.m_height as usize * scale;
+
+    let mut pixels: Vec<u8> = Vec::with_capacity(width * height * 3);
+    for row in grid.rows()
+    {
+        let row_pixels: Vec<u8> = row.iter().flat_map(|cell| colour_of(cell).repeat(scale)).collect();
+        for _ in 0..scale
+        {
+            pixels.extend_from_slice(&row_pixels);
+        }
+    }
+
+    if path.extension().is_some_and(|ext| ext == "png")
+    {
+        return write_png(path, width as u32, height as u32, &pixels).map_err(|err| format!("couldn't write {}: {err}", path.display()));
+    }
+
+    let mut data = format!("P6\n{width} {height}\n255\n").into_bytes();
+    data.extend_from_slice(&pixels);
+    return fs::write(path, data).map_err(|err| format!("couldn't write {}: {err}", path.display()));
+}
+
+fn write_png(path: &Path, width: u32, height: u32, pixels: &[u8]) -> Result<(), png::EncodingError>
+{
+    let file = File::create(path)?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    return writer.write_image_data(pixels);
+}
+
+impl Grid<char>
+{
+    pub fn write_image(&self, path: &Path, palette: &Palette, scale: u32) -> Result<(), String>
+    {
+        return write_image(self, path, scale, |c| palette.colour_of(*c));
+    }
+}
+
+impl Grid<i32>
+{
+    // For distance grids, blue at 0 up to red at the largest value. Negative cells (walls) are black.
+    pub fn write_heatmap(&self, path: &Path, scale: u32) -> Result<(), String>
+    {
+        let max = self.rows().flatten().copied().max().unwrap_or(0).max(1);
+
+        return write_image(self, path, scale, |value|
+        {
+            if *value < 0
+            {
+                return [0, 0, 0];
+            }
+
+            let t = *value as f32 / max as f32;
+            return [(255.0 * t) as u8, (64.0 * (1.0 - (2.0 * t - 1.0).abs())) as u8, (255.0 * (1.0 - t)) as u8];
+        });
+    }
+}
+
+// Writes numbered images into a folder, one per call, for watching a simulation step by step.
+pub struct FrameWriter
+{
+    m_dir: PathBuf,
+    m_palette: Palette,
+    m_scale: u32,
+    m_next_frame: u32,
+}
+
+impl FrameWriter
+{
+    // Creates `dir` if it isn't there yet.
+    pub fn from(dir: &Path, palette: Palette, scale: u32) -> Result<Self, String>
+    {
+        fs::create_dir_all(dir).map_err(|err| format!("couldn't create {}: {err}", dir.display()))?;
+
+        return Ok(Self
+        {
+            m_dir: dir.to_path_buf(),
+            m_palette: palette,
+            m_scale: scale,
+            m_next_frame: 0,
+        });
+    }
+
+    pub fn write_frame(&mut self, grid: &CharGrid) -> Result<(), String>
+    {
+        let path = self.m_dir.join(format!("frame_{:05}.png", self.m_next_frame));
+        self.m_next_frame += 1;
+
+        return grid.write_image(&path, &self.m_palette, self.m_scale);
+    }
+
+    pub fn frames_written(&self) -> u32
+    {
+        return self.m_next_frame;
+    }
+}
\ No newline at end of file
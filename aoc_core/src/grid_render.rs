@@ -0,0 +1,147 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use glam::IVec2;
+
+use crate::char_grid::CharGrid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colour
+{
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Grey,
+}
+
+impl Colour
+{
+    fn ansi_code(self) -> u8
+    {
+        match self
+        {
+            Colour::Red => { return 31; }
+            Colour::Green => { return 32; }
+            Colour::Yellow => { return 33; }
+            Colour::Blue => { return 34; }
+            Colour::Magenta => { return 35; }
+            Colour::Cyan => { return 36; }
+            Colour::White => { return 37; }
+            Colour::Grey => { return 90; }
+        }
+    }
+}
+
+// Colour for a char nobody has set one for, picked by what that kind of char usually is in a puzzle.
+fn default_colour(c: char) -> Option<Colour>
+{
+    match c
+    {
+        '.' => { return None; }
+        '#' => { return Some(Colour::Grey); }
+        'S' => { return Some(Colour::Green); }
+        'E' => { return Some(Colour::Red); }
+        '@' | '^' | '>' | 'v' | '<' => { return Some(Colour::Magenta); }
+        'O' | '[' | ']' => { return Some(Colour::Yellow); }
+        _ if c.is_ascii_digit() => { return Some(Colour::Blue); }
+        _ if c.is_alphabetic() => { return Some(Colour::Cyan); }
+        _ => { return None; }
+    }
+}
+
+// Cells drawn over the grid, e.g. a path. Without a glyph the char underneath is kept.
+struct Layer
+{
+    m_positions: HashSet<IVec2>,
+    m_glyph: Option<char>,
+    m_colour: Colour,
+}
+
+// Draws a grid with ANSI colours for printing to the terminal while debugging.
+pub struct GridRenderer<'a>
+{
+    m_grid: &'a CharGrid,
+    m_palette: HashMap<char, Option<Colour>>,
+    m_layers: Vec<Layer>,
+}
+
+impl<'a> GridRenderer<'a>
+{
+    pub fn from(grid: &'a CharGrid) -> Self
+    {
+        return Self
+        {
+            m_grid: grid,
+            m_palette: HashMap::new(),
+            m_layers: Vec::new(),
+        };
+    }
+
+    // None draws the char uncoloured.
+    pub fn set_colour(&mut self, c: char, colour: Option<Colour>)
+    {
+        self.m_palette.insert(c, colour);
+    }
+
+    // Later layers are drawn over earlier ones. Positions off the grid are ignored.
+    pub fn add_layer(&mut self, positions: impl IntoIterator<Item = IVec2>, glyph: Option<char>, colour: Colour)
+    {
+        self.m_layers.push(Layer
+        {
+            m_positions: positions.into_iter().collect(),
+            m_glyph: glyph,
+            m_colour: colour,
+        });
+    }
+
+    fn cell_at(&self, pos: IVec2) -> (char, Option<Colour>)
+    {
+        let c = self.m_grid[pos];
+        let mut cell = (c, self.m_palette.get(&c).copied().unwrap_or_else(|| default_colour(c)));
+
+        for layer in self.m_layers.iter().filter(|layer| layer.m_positions.contains(&pos))
+        {
+            cell = (layer.m_glyph.unwrap_or(cell.0), Some(layer.m_colour));
+        }
+
+        return cell;
+    }
+
+    // Colour codes are only written when the colour changes, and reset at the end of each row.
+    pub fn render(&self) -> String
+    {
+        let mut result = String::new();
+
+        for y in 0..self.m_grid.m_height
+        {
+            let mut current: Option<Colour> = None;
+            for x in 0..self.m_grid.m_width
+            {
+                let (c, colour) = self.cell_at(IVec2::new(x, y));
+                if colour != current
+                {
+                    match colour
+                    {
+                        Some(colour) => { write!(result, "\x1b[{}m", colour.ansi_code()).unwrap(); }
+                        None => { result.push_str("\x1b[0m"); }
+                    }
+                    current = colour;
+                }
+
+                result.push(c);
+            }
+
+            if current.is_some()
+            {
+                result.push_str("\x1b[0m");
+            }
+            result.push('\n');
+        }
+
+        return result;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use glam::IVec2;
+
+use crate::grid::Grid;
+
+// Each of these makes a new grid, the original is left alone.
+impl<T: Clone> Grid<T>
+{
+    // Clockwise, so the left column becomes the top row.
+    pub fn rotate_right(&self) -> Self
+    {
+        let height = self.m_height;
+        return Self::from_fn(self.m_height, self.m_width, |pos| self[IVec2::new(pos.y, height - 1 - pos.x)].clone());
+    }
+
+    // Anticlockwise, so the right column becomes the top row.
+    pub fn rotate_left(&self) -> Self
+    {
+        let width = self.m_width;
+        return Self::from_fn(self.m_height, self.m_width, |pos| self[IVec2::new(width - 1 - pos.y, pos.x)].clone());
+    }
+
+    pub fn rotate_180(&self) -> Self
+    {
+        let far_corner = IVec2::new(self.m_width - 1, self.m_height - 1);
+        return Self::from_fn(self.m_width, self.m_height, |pos| self[far_corner - pos].clone());
+    }
+
+    // Mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self
+    {
+        let width = self.m_width;
+        return Self::from_fn(self.m_width, self.m_height, |pos| self[IVec2::new(width - 1 - pos.x, pos.y)].clone());
+    }
+
+    // Mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    {
+        let height = self.m_height;
+        return Self::from_fn(self.m_width, self.m_height, |pos| self[IVec2::new(pos.x, height - 1 - pos.y)].clone());
+    }
+
+    // Rows become columns.
+    pub fn transpose(&self) -> Self
+    {
+        return Self::from_fn(self.m_height, self.m_width, |pos| self[IVec2::new(pos.y, pos.x)].clone());
+    }
+
+    // The `size` rectangle starting at `top_left`, any part of it off the grid is left out. Empty
+    // if none of it is on the grid.
+    pub fn crop(&self, top_left: IVec2, size: IVec2) -> Self
+    {
+        let start = top_left.max(IVec2::ZERO);
+        let end = (top_left + size).min(IVec2::new(self.m_width, self.m_height));
+        let size = if (end - start).cmpgt(IVec2::ZERO).all() { end - start } else { IVec2::ZERO };
+
+        return Self::from_fn(size.x, size.y, |pos| self[start + pos].clone());
+    }
+
+    // A copy with `other` pasted over it, its top left at `offset`. Cells that land off the grid are dropped.
+    pub fn blit(&self, other: &Grid<T>, offset: IVec2) -> Self
+    {
+        let mut result = self.clone();
+        for (pos, cell) in other.cells()
+        {
+            if let Some(target) = result.get_mut(pos + offset)
+            {
+                *target = cell.clone();
+            }
+        }
+
+        return result;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use glam::IVec2;
+
+use crate::aoc_utils::wrap_vec;
+use crate::grid::{Grid, NEIGHBOURS_4, NEIGHBOURS_8};
+
+// For grids on a torus, where walking off one edge comes back on the opposite one.
+impl<T> Grid<T>
+{
+    pub fn size(&self) -> IVec2
+    {
+        return IVec2::new(self.m_width, self.m_height);
+    }
+
+    // The position inside the grid that `pos` wraps around to.
+    pub fn wrap(&self, pos: IVec2) -> IVec2
+    {
+        return wrap_vec(pos, self.size());
+    }
+
+    pub fn get_wrapped(&self, pos: IVec2) -> &T
+    {
+        return &self[self.wrap(pos)];
+    }
+
+    pub fn get_wrapped_mut(&mut self, pos: IVec2) -> &mut T
+    {
+        let pos = self.wrap(pos);
+        return &mut self[pos];
+    }
+
+    // Where `steps` moves of `offset` from `pos` end up.
+    pub fn step_wrapped(&self, pos: IVec2, offset: IVec2, steps: i32) -> IVec2
+    {
+        return self.wrap(pos + offset * steps);
+    }
+
+    // Up, right, down and left of `pos`, wrapping instead of leaving any out.
+    pub fn neighbours_4_wrapped(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_
+    {
+        return NEIGHBOURS_4.into_iter().map(move |offset| self.wrap(pos + offset));
+    }
+
+    pub fn neighbours_8_wrapped(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_
+    {
+        return NEIGHBOURS_8.into_iter().map(move |offset| self.wrap(pos + offset));
+    }
+
+    // Shortest offset from `from` to `to`, which may go across an edge.
+    pub fn wrapped_delta(&self, from: IVec2, to: IVec2) -> IVec2
+    {
+        let size = self.size();
+        let delta = self.wrap(to - from);
+
+        return IVec2::new(
+            if delta.x * 2 > size.x { delta.x - size.x } else { delta.x },
+            if delta.y * 2 > size.y { delta.y - size.y } else { delta.y });
+    }
+
+    pub fn wrapped_manhattan(&self, from: IVec2, to: IVec2) -> i32
+    {
+        let delta = self.wrapped_delta(from, to);
+        return delta.x.abs() + delta.y.abs();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,334 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use glam::IVec2;
+
+use crate::grid::{grid_rows, Grid};
+use crate::parse_error::ParseError;
+use crate::search::{bfs, SuccessorFn};
+
+// Pointy topped hexes in axial coordinates, x is q and y is r. Moving along a row changes q,
+// moving down a row adds 1 to r and leans to the south east.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HexDir
+{
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast
+}
+
+impl HexDir
+{
+    // Clockwise from East.
+    pub const ALL: [HexDir; 6] = [HexDir::East, HexDir::SouthEast, HexDir::SouthWest, HexDir::West, HexDir::NorthWest, HexDir::NorthEast];
+
+    pub fn iter() -> impl Iterator<Item = HexDir>
+    {
+        return Self::ALL.into_iter();
+    }
+
+    // "E", "SE", "SW" and so on, either case.
+    pub fn from_compass(text: &str) -> Option<Self>
+    {
+        let text = text.to_ascii_uppercase();
+        return Self::ALL.into_iter().find(|dir| dir.compass() == text);
+    }
+
+    pub fn compass(&self) -> &'static str
+    {
+        match self
+        {
+            HexDir::East => "E",
+            HexDir::SouthEast => "SE",
+            HexDir::SouthWest => "SW",
+            HexDir::West => "W",
+            HexDir::NorthWest => "NW",
+            HexDir::NorthEast => "NE"
+        }
+    }
+
+    pub fn to_vec(&self) -> IVec2
+    {
+        match self
+        {
+            HexDir::East => IVec2::new(1, 0),
+            HexDir::SouthEast => IVec2::new(0, 1),
+            HexDir::SouthWest => IVec2::new(-1, 1),
+            HexDir::West => IVec2::new(-1, 0),
+            HexDir::NorthWest => IVec2::new(0, -1),
+            HexDir::NorthEast => IVec2::new(1, -1)
+        }
+    }
+
+    pub fn from_vec(delta: IVec2) -> Option<Self>
+    {
+        return Self::ALL.into_iter().find(|dir| dir.to_vec() == delta);
+    }
+
+    pub fn add_to(&self, pos: IVec2) -> IVec2
+    {
+        return pos + self.to_vec();
+    }
+
+    // Sixths of a turn clockwise, negative for anticlockwise.
+    pub fn rotate(&self, sixths: i32) -> HexDir
+    {
+        let idx = Self::ALL.iter().position(|dir| dir == self).unwrap() as i32;
+        return Self::ALL[(idx + sixths).rem_euclid(6) as usize];
+    }
+
+    pub fn invert(&self) -> HexDir
+    {
+        return self.rotate(3);
+    }
+
+    // 60 degrees clockwise.
+    pub fn rot_right(&self) -> HexDir
+    {
+        return self.rotate(1);
+    }
+
+    pub fn rot_left(&self) -> HexDir
+    {
+        return self.rotate(-1);
+    }
+}
+
+impl fmt::Display for HexDir
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        return write!(f, "{}", self.compass());
+    }
+}
+
+// Fewest hex steps between two axial positions.
+pub fn hex_distance(a: IVec2, b: IVec2) -> i32
+{
+    let delta = b - a;
+    return (delta.x.abs() + delta.y.abs() + (delta.x + delta.y).abs()) / 2;
+}
+
+// Rectangle of hexes where odd rows are pushed half a hex to the right, which is how they are
+// drawn in text:
+//  a b c
+//   d e f
+//  g h i
+// Positions going in and out are axial, the rows are only how the cells are stored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HexGrid<T>
+{
+    m_rows: Grid<T>,
+}
+
+impl<T> HexGrid<T>
+{
+    // One cell per character with a space between cells, odd rows indented by one more space than
+    // even rows. Leading indentation shared by every row is fine.
+    pub fn from_parser(input: &str, mut parse_cell: impl FnMut(char) -> Result<T, String>) -> Result<Self, ParseError>
+    {
+        let rows = grid_rows(input);
+        let indent = rows.iter()
+            .filter(|(_, row)| !row.is_empty())
+            .map(|(_, row)| row.chars().take_while(|c| *c == ' ').count())
+            .min()
+            .unwrap_or(0);
+
+        let mut width = 0;
+        let mut cells: Vec<T> = Vec::new();
+        for (row_idx, (line_num, row)) in rows.iter().enumerate()
+        {
+            let first_column = indent + row_idx % 2;
+            let mut row_width = 0;
+            for (idx, c) in row.chars().enumerate()
+            {
+                let is_cell = idx >= first_column && (idx - first_column) % 2 == 0;
+                if !is_cell
+                {
+                    if c != ' '
+                    {
+                        return Err(ParseError::new(*line_num, idx + 1, format!("expected a space between hexes, found '{c}'")));
+                    }
+                    continue;
+                }
+
+                let cell = parse_cell(c).map_err(|reason| ParseError::new(*line_num, idx + 1, reason))?;
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            if row_idx == 0
+            {
+                width = row_width;
+            }
+            else if row_width != width
+            {
+                return Err(ParseError::new(*line_num, row.chars().count() + 1, format!("row has {row_width} hexes but the first row has {width}")));
+            }
+        }
+
+        let mut cells = cells.into_iter();
+        let this = Self
+        {
+            m_rows: Grid::from_fn(width, rows.len() as i32, |_| cells.next().unwrap()),
+        };
+
+        return Ok(this);
+    }
+
+    pub fn from_value(value: T, width: i32, height: i32) -> Self
+        where T: Clone
+    {
+        return Self { m_rows: Grid::from_value(value, width, height) };
+    }
+
+    // Hexes in each row.
+    pub fn width(&self) -> i32
+    {
+        return self.m_rows.m_width;
+    }
+
+    pub fn height(&self) -> i32
+    {
+        return self.m_rows.m_height;
+    }
+
+    pub fn contains(&self, pos: IVec2) -> bool
+    {
+        return self.m_rows.inside_grid_vec(axial_to_row(pos));
+    }
+
+    pub fn get(&self, pos: IVec2) -> Option<&T>
+    {
+        return self.m_rows.get(axial_to_row(pos));
+    }
+
+    pub fn get_mut(&mut self, pos: IVec2) -> Option<&mut T>
+    {
+        return self.m_rows.get_mut(axial_to_row(pos));
+    }
+
+    pub fn map<U>(&self, convert: impl FnMut(&T) -> U) -> HexGrid<U>
+    {
+        return HexGrid { m_rows: self.m_rows.map(convert) };
+    }
+
+    // Every axial position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec2>
+    {
+        return self.m_rows.positions().map(row_to_axial);
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (IVec2, &T)> + '_
+    {
+        return self.m_rows.cells().map(|(pos, cell)| (row_to_axial(pos), cell));
+    }
+
+    pub fn positions_where<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = IVec2> + 'a
+    {
+        return self.cells().filter(move |(_, cell)| predicate(cell)).map(|(pos, _)| pos);
+    }
+
+    pub fn find_all(&self, item: T) -> Vec<IVec2>
+        where T: PartialEq
+    {
+        return self.positions_where(|cell| *cell == item).collect();
+    }
+
+    pub fn count(&self, item: T) -> usize
+        where T: PartialEq
+    {
+        return self.m_rows.count(item);
+    }
+
+    // The six hexes round `pos`, clockwise from East, leaving out any off the edge.
+    pub fn neighbours(&self, pos: IVec2) -> impl Iterator<Item = IVec2> + '_
+    {
+        return HexDir::iter().map(move |dir| dir.add_to(pos)).filter(|next| self.contains(*next));
+    }
+
+    // Fewest hex steps from `start` to every hex, only walking on hexes that are `passable`.
+    // None where it can't be reached.
+    pub fn bfs_distances(&self, start: IVec2, passable: impl Fn(&T) -> bool) -> HexGrid<Option<u32>>
+    {
+        let passable = &passable;
+        let walk = SuccessorFn::from(move |pos| self.neighbours(pos).filter(move |next| passable(&self[*next])).map(|next| (next, 1)));
+        let reached = bfs(&walk, start, |_| false);
+
+        return HexGrid { m_rows: Grid::from_fn(self.width(), self.height(), |pos| reached.cost(row_to_axial(pos)).map(|cost| cost as u32)) };
+    }
+}
+
+impl HexGrid<char>
+{
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    {
+        return Self::from_parser(input, Ok);
+    }
+}
+
+// Back to the indented layout from_parser reads.
+impl fmt::Display for HexGrid<char>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let mut result = String::new();
+        for (row_idx, row) in self.m_rows.rows().enumerate()
+        {
+            if row_idx % 2 == 1
+            {
+                result.push(' ');
+            }
+
+            let cells: Vec<String> = row.iter().map(|c| c.to_string()).collect();
+            result.push_str(&cells.join(" "));
+            result.push('\n');
+        }
+
+        return write!(f, "{result}");
+    }
+}
+
+// Panics outside the grid, use get() when the position might be off the edge.
+impl<T> Index<IVec2> for HexGrid<T>
+{
+    type Output = T;
+
+    fn index(&self, pos: IVec2) -> &T
+    {
+        let Some(cell) = self.get(pos) else
+        {
+            panic!("{pos} is outside the {}x{} hex grid", self.width(), self.height());
+        };
+
+        return cell;
+    }
+}
+
+impl<T> IndexMut<IVec2> for HexGrid<T>
+{
+    fn index_mut(&mut self, pos: IVec2) -> &mut T
+    {
+        let (width, height) = (self.width(), self.height());
+        let Some(cell) = self.get_mut(pos) else
+        {
+            panic!("{pos} is outside the {width}x{height} hex grid");
+        };
+
+        return cell;
+    }
+}
+
+// Column and row in the stored rectangle.
+fn axial_to_row(pos: IVec2) -> IVec2
+{
+    return IVec2::new(pos.x + (pos.y - (pos.y & 1)) / 2, pos.y);
+}
+
+fn row_to_axial(pos: IVec2) -> IVec2
+{
+    return IVec2::new(pos.x - (pos.y - (pos.y & 1)) / 2, pos.y);
+}
\ No newline at end of file
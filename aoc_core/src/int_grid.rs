@@ -0,0 +1,30 @@
+use crate::grid::Grid;
+use crate::parse_error::*;
+
+pub type IntGrid = Grid<i32>;
+
+impl Grid<i32>
+{
+    // One digit per cell.
+    pub fn from(input : &str) -> Result<Self, ParseError>
+    {
+        return Self::from_parser(input, |c| c.to_digit(10).map(|digit| digit as i32).ok_or(format!("expected a digit, found '{c}'")));
+    }
+
+    // Whitespace or comma separated integers, which can be negative or more than one digit.
+    pub fn from_numbers(input: &str) -> Result<Self, ParseError>
+    {
+        return Self::from_tokens(input, parse_number);
+    }
+
+    // As from_numbers, but a cell written as `placeholder` (e.g. ".") has no number and is given `missing_value`.
+    pub fn from_numbers_with_placeholder(input: &str, placeholder: &str, missing_value: i32) -> Result<Self, ParseError>
+    {
+        return Self::from_tokens(input, |token| if token == placeholder { Ok(missing_value) } else { parse_number(token) });
+    }
+}
+
+fn parse_number(token: &str) -> Result<i32, String>
+{
+    return token.parse::<i32>().map_err(|_| format!("expected a number, found '{token}'"));
+}
\ No newline at end of file
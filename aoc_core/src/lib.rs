@@ -0,0 +1,19 @@
+mod tests;
+pub mod aoc_utils;
+pub mod byte_grid;
+pub mod char_grid;
+pub mod int_grid;
+pub mod direction;
+pub mod grid;
+pub mod grid_image;
+pub mod grid_render;
+pub mod grid_transform;
+pub mod grid_wrap;
+pub mod hex_grid;
+pub mod parse_error;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod tile_grid;
+pub mod voxel_grid;
+pub mod config;
\ No newline at end of file
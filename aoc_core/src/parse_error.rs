@@ -0,0 +1,191 @@
+use std::fmt;
+use std::str::FromStr;
+
+// Where and why the puzzle input couldn't be read. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError
+{
+    pub m_line: usize,
+    pub m_column: usize,
+    pub m_reason: String,
+}
+
+impl ParseError
+{
+    pub fn new(line: usize, column: usize, reason: impl Into<String>) -> Self
+    {
+        return Self
+        {
+            m_line: line,
+            m_column: column,
+            m_reason: reason.into(),
+        };
+    }
+
+    // Point at `token`, which has to be a slice of `line`.
+    pub fn at_token(line_num: usize, line: &str, token: &str, reason: impl Into<String>) -> Self
+    {
+        return Self::new(line_num, column_of(line, token), reason);
+    }
+
+    // Point at a byte offset into the whole input.
+    pub fn at_offset(input: &str, offset: usize, reason: impl Into<String>) -> Self
+    {
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        return Self::new(line, column, reason);
+    }
+
+    // For an error in a section of the input that starts on `first_line`.
+    pub fn offset_lines(self, first_line: usize) -> Self
+    {
+        return Self::new(self.m_line + first_line - 1, self.m_column, self.m_reason);
+    }
+}
+
+impl fmt::Display for ParseError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        return write!(f, "line {}, column {}: {}", self.m_line, self.m_column, self.m_reason);
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Lines paired with their 1-based line number.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)>
+{
+    return input.lines().enumerate().map(|(idx, line)| (idx + 1, line));
+}
+
+// 1-based column of `token` inside `line`. Falls back to 1 if it isn't a slice of it.
+pub fn column_of(line: &str, token: &str) -> usize
+{
+    let line_start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+
+    if token_start < line_start || token_start > line_start + line.len()
+    {
+        return 1;
+    }
+
+    return line[..token_start - line_start].chars().count() + 1;
+}
+
+pub fn parse_token<T: FromStr>(line_num: usize, line: &str, token: &str) -> Result<T, ParseError>
+{
+    return token.parse::<T>()
+                .map_err(|_| ParseError::at_token(line_num, line, token, format!("expected a number, found '{token}'")));
+}
+
+// Every number in the line, ignoring whatever text sits between them.
+pub fn line_nums<T: FromStr>(line_num: usize, line: &str) -> Result<Vec<T>, ParseError>
+{
+    let mut result = Vec::new();
+    let bytes = line.as_bytes();
+    let mut idx = 0;
+
+    while idx < bytes.len()
+    {
+        let starts_negative = bytes[idx] == b'-' && idx + 1 < bytes.len() && bytes[idx + 1].is_ascii_digit();
+        if !bytes[idx].is_ascii_digit() && !starts_negative
+        {
+            idx += 1;
+            continue;
+        }
+
+        let start = idx;
+        idx += 1;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit()
+        {
+            idx += 1;
+        }
+
+        result.push(parse_token(line_num, line, &line[start..idx])?);
+    }
+
+    return Ok(result);
+}
+
+// Input that is just a number on each line, blank lines are skipped.
+pub fn one_num_per_line<T: FromStr>(input: &str) -> Result<Vec<T>, ParseError>
+{
+    let mut result = Vec::new();
+
+    for (line_num, line) in numbered_lines(input)
+    {
+        let token = line.trim();
+        if token.is_empty()
+        {
+            continue;
+        }
+
+        result.push(parse_token(line_num, line, token)?);
+    }
+
+    return Ok(result);
+}
+
+// Like line_nums but the line has to hold exactly `count` numbers.
+pub fn expect_line_nums<T: FromStr>(line_num: usize, line: &str, count: usize) -> Result<Vec<T>, ParseError>
+{
+    let result: Vec<T> = line_nums(line_num, line)?;
+
+    if result.len() != count
+    {
+        return Err(ParseError::new(line_num, 1, format!("expected {count} numbers, found {}", result.len())));
+    }
+
+    return Ok(result);
+}
+
+// For the day binaries, print the error and bail rather than panicking with a backtrace.
+pub fn unwrap_or_exit<T>(result: Result<T, ParseError>) -> T
+{
+    match result
+    {
+        Ok(value) => { return value; }
+        Err(err) =>
+        {
+            eprintln!("Couldn't parse input, {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+// Split on blank lines, giving each block along with the line number it starts on.
+pub fn split_sections(input: &str) -> Vec<(usize, &str)>
+{
+    let mut result = Vec::new();
+    let mut block_start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (idx, line) in input.split_inclusive('\n').enumerate()
+    {
+        let is_blank = line.trim().is_empty();
+
+        match block_start
+        {
+            None if !is_blank => { block_start = Some((idx + 1, offset)); }
+            Some((line_num, start)) if is_blank =>
+            {
+                result.push((line_num, &input[start..offset]));
+                block_start = None;
+            }
+            _ => {}
+        }
+
+        offset += line.len();
+    }
+
+    if let Some((line_num, start)) = block_start
+    {
+        result.push((line_num, &input[start..]));
+    }
+
+    return result;
+}
\ No newline at end of file
@@ -0,0 +1,349 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use glam::IVec2;
+
+use crate::grid::Grid;
+
+// Anything that can be searched: a state, e.g. a position or a position and facing, and the
+// states one move away from it with what each move costs.
+pub trait SearchSpace
+{
+    type State: Copy + Eq + Hash;
+
+    fn successors(&self, state: Self::State) -> impl Iterator<Item = (Self::State, u64)>;
+
+    // Lower bound on the cost left to reach the goal, only used by astar. It must never be more
+    // than the real cost, and never drop by more than a move costs, or astar can miss the best
+    // path.
+    fn heuristic(&self, _state: Self::State) -> u64
+    {
+        return 0;
+    }
+}
+
+// A search space from a closure, for searches that don't need a type of their own.
+pub struct SuccessorFn<S, F>
+{
+    m_successors: F,
+    m_state: PhantomData<S>,
+}
+
+impl<S, F, I> SuccessorFn<S, F>
+    where S: Copy + Eq + Hash, F: Fn(S) -> I, I: Iterator<Item = (S, u64)>
+{
+    pub fn from(successors: F) -> Self
+    {
+        return Self
+        {
+            m_successors: successors,
+            m_state: PhantomData,
+        };
+    }
+}
+
+impl<S, F, I> SearchSpace for SuccessorFn<S, F>
+    where S: Copy + Eq + Hash, F: Fn(S) -> I, I: Iterator<Item = (S, u64)>
+{
+    type State = S;
+
+    fn successors(&self, state: S) -> impl Iterator<Item = (S, u64)>
+    {
+        return (self.m_successors)(state);
+    }
+}
+
+// Up, right, down and left steps of cost 1 between the `passable` cells of a grid. With a goal
+// set the heuristic is the manhattan distance to it.
+pub struct GridWalk<'a, T, P>
+{
+    m_grid: &'a Grid<T>,
+    m_passable: P,
+    m_goal: Option<IVec2>,
+}
+
+impl<'a, T, P> GridWalk<'a, T, P>
+    where P: Fn(&T) -> bool
+{
+    pub fn from(grid: &'a Grid<T>, passable: P) -> Self
+    {
+        return Self
+        {
+            m_grid: grid,
+            m_passable: passable,
+            m_goal: None,
+        };
+    }
+
+    pub fn set_goal(&mut self, goal: IVec2)
+    {
+        self.m_goal = Some(goal);
+    }
+}
+
+impl<T, P> SearchSpace for GridWalk<'_, T, P>
+    where P: Fn(&T) -> bool
+{
+    type State = IVec2;
+
+    fn successors(&self, pos: IVec2) -> impl Iterator<Item = (IVec2, u64)>
+    {
+        return self.m_grid.neighbours_4(pos).filter(|next| (self.m_passable)(&self.m_grid[*next])).map(|next| (next, 1));
+    }
+
+    fn heuristic(&self, pos: IVec2) -> u64
+    {
+        return self.m_goal.map_or(0, |goal| (goal - pos).abs().element_sum() as u64);
+    }
+}
+
+// What a search found. Costs are only kept for states whose best cost is known, so a state that
+// was seen but not finished with reads as unreached.
+#[derive(Clone, Debug)]
+pub struct SearchResult<S>
+{
+    m_costs: HashMap<S, u64>,
+    // The first predecessor found on a best path, and every one of them if they were asked for.
+    m_came_from: HashMap<S, S>,
+    m_predecessors: HashMap<S, Vec<S>>,
+    // Goals reached, all with the same best cost.
+    m_goals: Vec<S>,
+}
+
+impl<S> SearchResult<S>
+    where S: Copy + Eq + Hash
+{
+    fn new() -> Self
+    {
+        return Self
+        {
+            m_costs: HashMap::new(),
+            m_came_from: HashMap::new(),
+            m_predecessors: HashMap::new(),
+            m_goals: Vec::new(),
+        };
+    }
+
+    pub fn cost(&self, state: S) -> Option<u64>
+    {
+        return self.m_costs.get(&state).copied();
+    }
+
+    // Every state with a known best cost.
+    pub fn costs(&self) -> &HashMap<S, u64>
+    {
+        return &self.m_costs;
+    }
+
+    pub fn goal(&self) -> Option<S>
+    {
+        return self.m_goals.first().copied();
+    }
+
+    // Goals that tie for the best cost, in the order they were reached.
+    pub fn goals(&self) -> &[S]
+    {
+        return &self.m_goals;
+    }
+
+    pub fn goal_cost(&self) -> Option<u64>
+    {
+        return self.goal().and_then(|goal| self.cost(goal));
+    }
+
+    // One best path from the start to `state`, both ends included.
+    pub fn path_to(&self, state: S) -> Option<Vec<S>>
+    {
+        if !self.m_costs.contains_key(&state)
+        {
+            return None;
+        }
+
+        let mut path = vec![state];
+        while let Some(prev) = self.m_came_from.get(path.last().unwrap())
+        {
+            path.push(*prev);
+        }
+
+        path.reverse();
+        return Some(path);
+    }
+
+    // Every state before `state` on a best path to it. Empty unless the search kept them all.
+    pub fn predecessors(&self, state: S) -> &[S]
+    {
+        return self.m_predecessors.get(&state).map_or(&[], |prevs| prevs.as_slice());
+    }
+
+    // Every state on any best path to any of `ends`, needs the search to have kept all
+    // predecessors.
+    pub fn states_on_best_paths(&self, ends: &[S]) -> HashSet<S>
+    {
+        let mut found: HashSet<S> = HashSet::new();
+        let mut to_visit: Vec<S> = ends.iter().copied().filter(|end| self.m_costs.contains_key(end)).collect();
+
+        while let Some(state) = to_visit.pop()
+        {
+            if found.insert(state)
+            {
+                to_visit.extend_from_slice(self.predecessors(state));
+            }
+        }
+
+        return found;
+    }
+}
+
+// Fewest moves from `start`, ignoring the cost of each move. Stops at the first state that
+// `is_goal`, or once everything reachable has been seen.
+pub fn bfs<G: SearchSpace>(space: &G, start: G::State, is_goal: impl Fn(G::State) -> bool) -> SearchResult<G::State>
+{
+    let mut result = SearchResult::new();
+    let mut to_visit: VecDeque<G::State> = VecDeque::from([start]);
+    result.m_costs.insert(start, 0);
+
+    while let Some(state) = to_visit.pop_front()
+    {
+        if is_goal(state)
+        {
+            result.m_goals.push(state);
+            break;
+        }
+
+        let next_cost = result.m_costs[&state] + 1;
+        for (next, _) in space.successors(state)
+        {
+            if let Entry::Vacant(entry) = result.m_costs.entry(next)
+            {
+                entry.insert(next_cost);
+                result.m_came_from.insert(next, state);
+                to_visit.push_back(next);
+            }
+        }
+    }
+
+    return result;
+}
+
+// Cheapest cost from `start`. Stops once every goal as cheap as the first one has been reached,
+// or once everything reachable is finished with.
+pub fn dijkstra<G: SearchSpace>(space: &G, start: G::State, is_goal: impl Fn(G::State) -> bool) -> SearchResult<G::State>
+{
+    return best_first(space, start, is_goal, false, false);
+}
+
+// Same as dijkstra but also keeps every predecessor on a best path, for when all the best paths
+// matter and not just one.
+pub fn dijkstra_all_paths<G: SearchSpace>(space: &G, start: G::State, is_goal: impl Fn(G::State) -> bool) -> SearchResult<G::State>
+{
+    return best_first(space, start, is_goal, false, true);
+}
+
+// Dijkstra guided by the space's heuristic, so it usually finishes with far fewer states.
+pub fn astar<G: SearchSpace>(space: &G, start: G::State, is_goal: impl Fn(G::State) -> bool) -> SearchResult<G::State>
+{
+    return best_first(space, start, is_goal, true, false);
+}
+
+// Min-heap entry, ordered by priority only so states don't need to be Ord.
+struct Queued<S>
+{
+    m_priority: u64,
+    m_cost: u64,
+    m_state: S,
+}
+
+impl<S> PartialEq for Queued<S>
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        return self.m_priority == other.m_priority;
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> Ord for Queued<S>
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        // Reversed so BinaryHeap pops the lowest.
+        return other.m_priority.cmp(&self.m_priority);
+    }
+}
+
+impl<S> PartialOrd for Queued<S>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        return Some(self.cmp(other));
+    }
+}
+
+fn best_first<G: SearchSpace>(space: &G, start: G::State, is_goal: impl Fn(G::State) -> bool, use_heuristic: bool, keep_predecessors: bool) -> SearchResult<G::State>
+{
+    let mut result = SearchResult::new();
+    let priority_of = |state: G::State, cost: u64| if use_heuristic { cost + space.heuristic(state) } else { cost };
+
+    // Cheapest cost seen so far, final once the state comes off the heap.
+    let mut best: HashMap<G::State, u64> = HashMap::from([(start, 0)]);
+    let mut open: BinaryHeap<Queued<G::State>> = BinaryHeap::from([Queued { m_priority: priority_of(start, 0), m_cost: 0, m_state: start }]);
+
+    while let Some(Queued { m_priority: priority, m_cost: cost, m_state: state }) = open.pop()
+    {
+        if result.m_costs.contains_key(&state) || cost > best[&state]
+        {
+            continue;
+        }
+
+        // Anything popped after the first goal that costs more can't be another best goal.
+        if result.goal_cost().is_some_and(|goal_cost| priority > goal_cost)
+        {
+            break;
+        }
+
+        result.m_costs.insert(state, cost);
+        if is_goal(state)
+        {
+            result.m_goals.push(state);
+            continue;
+        }
+
+        for (next, step_cost) in space.successors(state)
+        {
+            let next_cost = cost + step_cost;
+            let known = best.get(&next).copied().unwrap_or(u64::MAX);
+
+            // A state that is already finished can't get cheaper, but a zero cost move can still
+            // reach it for the same cost and that is another best path.
+            if result.m_costs.contains_key(&next)
+            {
+                if next_cost == known && keep_predecessors
+                {
+                    result.m_predecessors.entry(next).or_default().push(state);
+                }
+                continue;
+            }
+
+            if next_cost < known
+            {
+                best.insert(next, next_cost);
+                result.m_came_from.insert(next, state);
+                if keep_predecessors
+                {
+                    result.m_predecessors.insert(next, vec![state]);
+                }
+                open.push(Queued { m_priority: priority_of(next, next_cost), m_cost: next_cost, m_state: next });
+            }
+            else if next_cost == known && keep_predecessors
+            {
+                result.m_predecessors.entry(next).or_default().push(state);
+            }
+        }
+    }
+
+    return result;
+}
\ No newline at end of file
@@ -0,0 +1,187 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+
+use crate::config::{load_params, CONFIG_FILE};
+use crate::parse_error::{unwrap_or_exit, ParseError};
+
+// Each day's binary reads this from the directory it is run in.
+pub const INPUT_FILE: &str = "input.txt";
+
+// What a part of a day produces, whatever integer type it used internally.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer
+{
+    Signed(i64),
+    Unsigned(u128),
+    Bool(bool),
+    Text(String),
+}
+
+impl fmt::Display for Answer
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Answer::Signed(value) => { return write!(f, "{value}"); }
+            Answer::Unsigned(value) => { return write!(f, "{value}"); }
+            Answer::Bool(value) => { return write!(f, "{value}"); }
+            Answer::Text(value) => { return write!(f, "{value}"); }
+        }
+    }
+}
+
+impl From<i32> for Answer
+{
+    fn from(value: i32) -> Self
+    {
+        return Answer::Signed(value as i64);
+    }
+}
+
+impl From<i64> for Answer
+{
+    fn from(value: i64) -> Self
+    {
+        return Answer::Signed(value);
+    }
+}
+
+impl From<u32> for Answer
+{
+    fn from(value: u32) -> Self
+    {
+        return Answer::Unsigned(value as u128);
+    }
+}
+
+impl From<u64> for Answer
+{
+    fn from(value: u64) -> Self
+    {
+        return Answer::Unsigned(value as u128);
+    }
+}
+
+impl From<u128> for Answer
+{
+    fn from(value: u128) -> Self
+    {
+        return Answer::Unsigned(value);
+    }
+}
+
+impl From<usize> for Answer
+{
+    fn from(value: usize) -> Self
+    {
+        return Answer::Unsigned(value as u128);
+    }
+}
+
+impl From<bool> for Answer
+{
+    fn from(value: bool) -> Self
+    {
+        return Answer::Bool(value);
+    }
+}
+
+impl From<String> for Answer
+{
+    fn from(value: String) -> Self
+    {
+        return Answer::Text(value);
+    }
+}
+
+impl From<&str> for Answer
+{
+    fn from(value: &str) -> Self
+    {
+        return Answer::Text(String::from(value));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartKind
+{
+    Solver,
+    // Needs someone at the keyboard, so it is skipped when running everything.
+    Interactive,
+    // No solution written yet, the part is never called.
+    Missing,
+}
+
+// Implemented once per day so runners, harnesses and benchmarks can treat every day the same.
+pub trait Solution
+{
+    // Values a day needs besides its input, e.g. grid dimensions. Defaults are the real puzzle's,
+    // and a config.toml can override any of them.
+    type Params: Default + DeserializeOwned;
+
+    const DAY: u32;
+    const PART_KINDS: [PartKind; 2] = [PartKind::Solver, PartKind::Solver];
+
+    // Whatever each part works on once its input has been read, kept separate so benchmarks can
+    // time parsing and solving on their own.
+    type Parsed1;
+    type Parsed2;
+
+    fn parse_part1(input: &String, params: &Self::Params) -> Result<Self::Parsed1, ParseError>;
+    fn solve_part1(parsed: &Self::Parsed1, params: &Self::Params) -> Answer;
+
+    fn parse_part2(input: &String, params: &Self::Params) -> Result<Self::Parsed2, ParseError>;
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer;
+
+    fn part1(input: &String, params: &Self::Params) -> Result<Answer, ParseError>
+    {
+        let parsed = Self::parse_part1(input, params)?;
+        return Ok(Self::solve_part1(&parsed, params));
+    }
+
+    fn part2(input: &String, params: &Self::Params) -> Result<Answer, ParseError>
+    {
+        let parsed = Self::parse_part2(input, params)?;
+        return Ok(Self::solve_part2(&parsed, params));
+    }
+
+    fn solve(part: u32, input: &String, params: &Self::Params) -> Result<Answer, ParseError>
+    {
+        match part
+        {
+            1 => { return Self::part1(input, params); }
+            2 => { return Self::part2(input, params); }
+            _ => { panic!("Day {} has no part {part}", Self::DAY); }
+        }
+    }
+}
+
+// Everything a day's main.rs does: solve ./input.txt with the params from ./config.toml.
+pub fn run_main<S: Solution>()
+{
+    let input = fs::read_to_string(INPUT_FILE).expect("Should have been able to read the file");
+    let params : S::Params = match load_params(Path::new(CONFIG_FILE))
+    {
+        Ok(params) => params,
+        Err(err) =>
+        {
+            eprintln!("Couldn't parse {CONFIG_FILE}, {err}");
+            std::process::exit(1);
+        }
+    };
+
+    for part in 1..=2
+    {
+        if S::PART_KINDS[part as usize - 1] == PartKind::Missing
+        {
+            continue;
+        }
+
+        let answer = unwrap_or_exit(S::solve(part, &input, &params));
+        println!("Part {part}: {answer}");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use glam::IVec2;
+
+use crate::grid::Grid;
+
+// Unbounded grid that only stores the cells that were set, everything else reads as the default.
+// For mostly empty spaces, or ones that reach past the edge of the map.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseGrid<T>
+{
+    m_cells: HashMap<IVec2, T>,
+    m_default: T,
+    // Smallest and largest positions ever set, inclusive.
+    m_bounds: Option<(IVec2, IVec2)>,
+}
+
+impl<T> SparseGrid<T>
+{
+    pub fn from(default: T) -> Self
+    {
+        return Self
+        {
+            m_cells: HashMap::new(),
+            m_default: default,
+            m_bounds: None,
+        };
+    }
+
+    // Every cell of `grid` that isn't `default`, at the same positions.
+    pub fn from_grid(grid: &Grid<T>, default: T) -> Self
+        where T: Clone + PartialEq
+    {
+        let mut result = Self::from(default.clone());
+        for (pos, cell) in grid.cells().filter(|(_, cell)| **cell != default)
+        {
+            result.set(pos, cell.clone());
+        }
+
+        return result;
+    }
+
+    pub fn get(&self, pos: IVec2) -> &T
+    {
+        return self.m_cells.get(&pos).unwrap_or(&self.m_default);
+    }
+
+    // Whether `pos` has been set, even if it was set to the default.
+    pub fn contains(&self, pos: IVec2) -> bool
+    {
+        return self.m_cells.contains_key(&pos);
+    }
+
+    // Gives back what was there before, if anything.
+    pub fn set(&mut self, pos: IVec2, value: T) -> Option<T>
+    {
+        self.m_bounds = match self.m_bounds
+        {
+            Some((min, max)) => Some((min.min(pos), max.max(pos))),
+            None => Some((pos, pos)),
+        };
+
+        return self.m_cells.insert(pos, value);
+    }
+
+    // The bounds don't shrink, they cover everything that was ever set.
+    pub fn remove(&mut self, pos: IVec2) -> Option<T>
+    {
+        return self.m_cells.remove(&pos);
+    }
+
+    pub fn len(&self) -> usize
+    {
+        return self.m_cells.len();
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        return self.m_cells.is_empty();
+    }
+
+    // Top left and bottom right corners, inclusive. None until something is set.
+    pub fn bounds(&self) -> Option<(IVec2, IVec2)>
+    {
+        return self.m_bounds;
+    }
+
+    // Set cells in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (IVec2, &T)>
+    {
+        return self.m_cells.iter().map(|(pos, cell)| (*pos, cell));
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = IVec2> + '_
+    {
+        return self.m_cells.keys().copied();
+    }
+
+    // The `size` rectangle starting at `top_left` as a dense grid.
+    pub fn to_grid(&self, top_left: IVec2, size: IVec2) -> Grid<T>
+        where T: Clone
+    {
+        return Grid::from_fn(size.x, size.y, |pos| self.get(top_left + pos).clone());
+    }
+
+    // Everything inside the bounds, with the position of its top left cell.
+    pub fn to_bounded_grid(&self) -> (Grid<T>, IVec2)
+        where T: Clone
+    {
+        let (min, max) = self.m_bounds.unwrap_or((IVec2::ZERO, IVec2::NEG_ONE));
+        return (self.to_grid(min, max - min + IVec2::ONE), min);
+    }
+}
+
+// Whatever is inside the bounds, so cells at negative positions are drawn too.
+impl fmt::Display for SparseGrid<char>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        return write!(f, "{}", self.to_bounded_grid().0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,700 @@
+#[cfg(test)]
+mod tests 
+{
+    use std::env;
+    use std::fs;
+
+    use glam::{IVec2, IVec3};
+
+    use crate::byte_grid::ByteGrid;
+    use crate::char_grid::CharGrid;
+    use crate::direction::Direction;
+    use crate::grid::Grid;
+    use crate::grid_image::{FrameWriter, Palette};
+    use crate::grid_render::{Colour, GridRenderer};
+    use crate::hex_grid::{hex_distance, HexDir, HexGrid};
+    use crate::int_grid::IntGrid;
+    use crate::parse_error::ParseError;
+    use crate::search::{astar, bfs, dijkstra, dijkstra_all_paths, GridWalk, SearchSpace, SuccessorFn};
+    use crate::sparse_grid::SparseGrid;
+    use crate::tile_grid::Tile;
+    use crate::voxel_grid::VoxelGrid;
+
+    // 4 wide and 3 high so rows and columns can't be mixed up.
+    const WIDE_STR: &str = r"abcd
+efgh
+ijkl";
+
+    #[test]
+    fn grid_neighbour_counts_test() 
+    {
+        let grid = CharGrid::from(WIDE_STR);
+
+        for (pos, count_4, count_8) in [(IVec2::new(0, 0), 2, 3), (IVec2::new(3, 2), 2, 3), (IVec2::new(1, 0), 3, 5), (IVec2::new(0, 1), 3, 5), (IVec2::new(2, 1), 4, 8)]
+        {
+            assert_eq!(grid.neighbours_4(pos).count(), count_4, "neighbours_4 of {pos}");
+            assert_eq!(grid.neighbours_8(pos).count(), count_8, "neighbours_8 of {pos}");
+        }
+
+        let corner: Vec<IVec2> = grid.neighbours_4(IVec2::new(3, 0)).collect();
+        assert_eq!(corner, vec![IVec2::new(3, 1), IVec2::new(2, 0)]);
+    }
+
+    #[test]
+    fn grid_rows_and_columns_test() 
+    {
+        let grid = CharGrid::from(WIDE_STR);
+        assert_eq!((grid.m_width, grid.m_height), (4, 3));
+
+        assert_eq!(rows_of(&grid), vec!["abcd", "efgh", "ijkl"]);
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["aei", "bfj", "cgk", "dhl"]);
+
+        let positions: Vec<IVec2> = grid.positions().take(5).collect();
+        assert_eq!(positions, vec![IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(2, 0), IVec2::new(3, 0), IVec2::new(0, 1)]);
+        assert_eq!(grid.cells().nth(6), Some((IVec2::new(2, 1), &'g')));
+    }
+    fn rows_of(grid: &CharGrid) -> Vec<String>
+    {
+        return grid.rows().map(|row| row.iter().collect()).collect();
+    }
+
+    #[test]
+    fn grid_queries_test() 
+    {
+        let grid = CharGrid::from("#a.a\n.#a.\nb..#");
+
+        // Reading order, row by row.
+        assert_eq!(grid.find_all('a'), vec![IVec2::new(1, 0), IVec2::new(3, 0), IVec2::new(2, 1)]);
+        assert_eq!(grid.find_all('#'), vec![IVec2::new(0, 0), IVec2::new(1, 1), IVec2::new(3, 2)]);
+        assert_eq!(grid.find_first('a'), Some(IVec2::new(1, 0)));
+        assert_eq!(grid.find_all('z'), vec![]);
+        assert_eq!((grid.count('a'), grid.count('.'), grid.count('z')), (3, 5, 0));
+
+        let by_char = grid.positions_by_char();
+        assert_eq!(by_char.len(), 4);
+        assert_eq!(by_char[&'a'], grid.find_all('a'));
+        assert_eq!(by_char[&'.'], grid.find_all('.'));
+        assert_eq!(by_char[&'b'], vec![IVec2::new(0, 2)]);
+    }
+
+    #[test]
+    fn grid_expect_unique_test() 
+    {
+        let grid = CharGrid::from("#a.a\n.#a.\nb..#");
+
+        assert_eq!(grid.expect_unique('b', "start (b)"), Ok(IVec2::new(0, 2)));
+        assert_eq!(grid.expect_unique('z', "end (z)"), Err(ParseError::new(1, 1, "no end (z) in the grid")));
+
+        // Points at the second one in reading order, with where the first was.
+        assert_eq!(grid.expect_unique('a', "robot (a)"), Err(ParseError::new(1, 4, "found a second robot (a), the first is at 1:2")));
+        assert_eq!(grid.expect_unique_where("letter", |c| c.is_alphabetic() && *c != 'a'), Ok(IVec2::new(0, 2)));
+        assert_eq!(grid.expect_unique_where("wall", |c| *c == '#'), Err(ParseError::new(2, 2, "found a second wall, the first is at 1:1")));
+    }
+
+    #[test]
+    fn grid_transform_test() 
+    {
+        let grid = CharGrid::from(WIDE_STR);
+
+        assert_eq!(rows_of(&grid.rotate_right()), vec!["iea", "jfb", "kgc", "lhd"]);
+        assert_eq!(rows_of(&grid.rotate_left()), vec!["dhl", "cgk", "bfj", "aei"]);
+        assert_eq!(rows_of(&grid.rotate_180()), vec!["lkji", "hgfe", "dcba"]);
+        assert_eq!(rows_of(&grid.flip_horizontal()), vec!["dcba", "hgfe", "lkji"]);
+        assert_eq!(rows_of(&grid.flip_vertical()), vec!["ijkl", "efgh", "abcd"]);
+        assert_eq!(rows_of(&grid.transpose()), vec!["aei", "bfj", "cgk", "dhl"]);
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn grid_crop_and_blit_test() 
+    {
+        let grid = CharGrid::from(WIDE_STR);
+
+        assert_eq!(rows_of(&grid.crop(IVec2::new(1, 1), IVec2::new(2, 2))), vec!["fg", "jk"]);
+        assert_eq!(rows_of(&grid.crop(IVec2::new(2, -1), IVec2::new(5, 3))), vec!["cd", "gh"]);
+        let off_grid = grid.crop(IVec2::new(5, 0), IVec2::new(2, 2));
+        assert_eq!((off_grid.m_width, off_grid.m_height), (0, 0));
+
+        let blank = CharGrid::from_char('.', 4, 3);
+        let pasted = blank.blit(&CharGrid::from("xy\nzw"), IVec2::new(3, 1));
+        assert_eq!(rows_of(&pasted), vec!["....", "...x", "...z"]);
+        assert_eq!(blank.blit(&grid, IVec2::ZERO), grid);
+    }
+
+    #[test]
+    fn grid_wrap_test() 
+    {
+        let grid = CharGrid::from(WIDE_STR);
+
+        assert_eq!(*grid.get_wrapped(IVec2::new(-1, -1)), 'l');
+        assert_eq!(grid.step_wrapped(IVec2::new(3, 0), IVec2::X, 5), IVec2::new(0, 0));
+
+        let corner_4: Vec<IVec2> = grid.neighbours_4_wrapped(IVec2::new(3, 0)).collect();
+        assert_eq!(corner_4, vec![IVec2::new(3, 2), IVec2::new(0, 0), IVec2::new(3, 1), IVec2::new(2, 0)]);
+        let corner_8: Vec<IVec2> = grid.neighbours_8_wrapped(IVec2::new(3, 0)).collect();
+        assert_eq!(corner_8, vec![IVec2::new(3, 2), IVec2::new(0, 2), IVec2::new(0, 0), IVec2::new(0, 1),
+                                  IVec2::new(3, 1), IVec2::new(2, 1), IVec2::new(2, 0), IVec2::new(2, 2)]);
+
+        // Across both edges, in either direction.
+        assert_eq!(grid.wrapped_delta(IVec2::new(3, 2), IVec2::new(0, 0)), IVec2::new(1, 1));
+        assert_eq!(grid.wrapped_delta(IVec2::new(0, 0), IVec2::new(3, 2)), IVec2::new(-1, -1));
+        assert_eq!(grid.wrapped_manhattan(IVec2::new(0, 0), IVec2::new(3, 2)), 2);
+
+        // Exactly half the width is the same distance both ways and stays positive.
+        assert_eq!(grid.wrapped_delta(IVec2::new(0, 1), IVec2::new(2, 1)), IVec2::new(2, 0));
+        assert_eq!(grid.wrapped_delta(IVec2::new(2, 1), IVec2::new(0, 1)), IVec2::new(2, 0));
+        assert_eq!(grid.wrapped_manhattan(IVec2::new(3, 1), IVec2::new(1, 1)), 2);
+
+        // The height is odd so there's no tie, two down is one up.
+        assert_eq!(grid.wrapped_delta(IVec2::new(0, 0), IVec2::new(0, 2)), IVec2::new(0, -1));
+        assert_eq!(grid.wrapped_delta(IVec2::new(0, 2), IVec2::new(0, 0)), IVec2::new(0, 1));
+    }
+
+    #[test]
+    fn sparse_grid_bounds_test() 
+    {
+        let mut sparse = SparseGrid::from('.');
+        assert_eq!(sparse.bounds(), None);
+        let (empty, top_left) = sparse.to_bounded_grid();
+        assert_eq!((empty.m_width, empty.m_height, top_left), (0, 0, IVec2::ZERO));
+
+        assert_eq!(sparse.set(IVec2::new(1, -1), 'a'), None);
+        assert_eq!(sparse.set(IVec2::new(-1, 1), 'b'), None);
+        assert_eq!(sparse.set(IVec2::new(1, -1), 'c'), Some('a'));
+        assert_eq!(sparse.bounds(), Some((IVec2::new(-1, -1), IVec2::new(1, 1))));
+        assert_eq!(*sparse.get(IVec2::new(7, 7)), '.');
+
+        let (grid, top_left) = sparse.to_bounded_grid();
+        assert_eq!(top_left, IVec2::new(-1, -1));
+        assert_eq!(rows_of(&grid), vec!["..c", "...", "b.."]);
+        assert_eq!(sparse.to_string(), "..c\n...\nb..\n");
+
+        // Removing a cell leaves the bounds where they were.
+        assert_eq!(sparse.remove(IVec2::new(-1, 1)), Some('b'));
+        assert_eq!(sparse.bounds(), Some((IVec2::new(-1, -1), IVec2::new(1, 1))));
+        assert_eq!(rows_of(&sparse.to_bounded_grid().0), vec!["..c", "...", "..."]);
+    }
+
+    #[test]
+    fn sparse_grid_from_grid_test() 
+    {
+        let grid = CharGrid::from("#...\n..#.\n....");
+        let sparse = SparseGrid::from_grid(&grid, '.');
+
+        assert_eq!(sparse.len(), 2);
+        assert!(sparse.contains(IVec2::new(2, 1)) && !sparse.contains(IVec2::new(1, 1)));
+        assert_eq!(sparse.bounds(), Some((IVec2::new(0, 0), IVec2::new(2, 1))));
+        assert_eq!(rows_of(&sparse.to_bounded_grid().0), vec!["#..", "..#"]);
+        assert_eq!(sparse.to_grid(IVec2::ZERO, IVec2::new(4, 3)), grid);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum TestTile
+    {
+        Open,
+        Wall,
+    }
+
+    impl Tile for TestTile
+    {
+        fn from_char(c: char) -> Option<Self>
+        {
+            match c
+            {
+                '.' => { return Some(TestTile::Open); }
+                '#' => { return Some(TestTile::Wall); }
+                _ => { return None; }
+            }
+        }
+
+        fn to_char(&self) -> char
+        {
+            match self
+            {
+                TestTile::Open => { return '.'; }
+                TestTile::Wall => { return '#'; }
+            }
+        }
+    }
+
+    #[test]
+    fn tile_grid_test() 
+    {
+        let (grid, markers) = Grid::<TestTile>::parse_tiles_with_markers("#S.#\n#.E#", &['S', 'E'], TestTile::Open).unwrap();
+        assert_eq!(rows_of(&grid.to_char_grid()), vec!["#..#", "#..#"]);
+        assert_eq!(markers.expect_unique(&['E'], "end (E)"), Ok((IVec2::new(2, 1), 'E')));
+        assert_eq!(markers.expect_unique(&['S'], "start (S)"), Ok((IVec2::new(1, 0), 'S')));
+
+        let result = Grid::<TestTile>::parse_tiles("#..#\n#.x#");
+        assert_eq!(result, Err(ParseError::new(2, 3, "unexpected character 'x'")));
+
+        // A marker that isn't in the list is just an unknown char.
+        let result = Grid::<TestTile>::parse_tiles_with_markers("#S.E", &['S'], TestTile::Open);
+        assert_eq!(result.map(|_| ()), Err(ParseError::new(1, 4, "unexpected character 'E'")));
+    }
+
+    #[test]
+    fn tile_grid_markers_test() 
+    {
+        let (_, markers) = Grid::<TestTile>::parse_tiles_with_markers("#S.#\n#..#", &['S', 'E'], TestTile::Open).unwrap();
+        assert_eq!(markers.expect_unique(&['E'], "end (E)"), Err(ParseError::new(1, 1, "no end (E) in the grid")));
+
+        let (_, markers) = Grid::<TestTile>::parse_tiles_with_markers("#S.#\n#.S#", &['S', 'E'], TestTile::Open).unwrap();
+        assert_eq!(markers.positions_of('S').collect::<Vec<_>>(), vec![IVec2::new(1, 0), IVec2::new(2, 1)]);
+        assert_eq!(markers.expect_unique(&['S'], "start (S)"), Err(ParseError::new(2, 3, "found a second start (S), the first is at 1:2")));
+    }
+
+    #[test]
+    fn grid_render_test() 
+    {
+        let grid = CharGrid::from("##.\n.S.");
+        let renderer = GridRenderer::from(&grid);
+
+        // One code per run of a colour, and a reset only if the row ends coloured.
+        assert_eq!(renderer.render(), "\x1b[90m##\x1b[0m.\n.\x1b[32mS\x1b[0m.\n");
+
+        let grid = CharGrid::from("..#\n#..");
+        let mut renderer = GridRenderer::from(&grid);
+        assert_eq!(renderer.render(), "..\x1b[90m#\x1b[0m\n\x1b[90m#\x1b[0m..\n");
+
+        renderer.set_colour('#', None);
+        renderer.set_colour('.', Some(Colour::Blue));
+        assert_eq!(renderer.render(), "\x1b[34m..\x1b[0m#\n#\x1b[34m..\x1b[0m\n");
+    }
+
+    #[test]
+    fn grid_render_layers_test() 
+    {
+        let grid = CharGrid::from("...\n...");
+        let mut renderer = GridRenderer::from(&grid);
+
+        // The second layer keeps the first layer's glyph but its own colour wins, and positions
+        // off the grid are skipped.
+        renderer.add_layer([IVec2::new(0, 0), IVec2::new(1, 0), IVec2::new(-1, 0)], Some('O'), Colour::Green);
+        renderer.add_layer([IVec2::new(1, 0), IVec2::new(3, 1), IVec2::new(2, 5)], None, Colour::Red);
+        renderer.add_layer([IVec2::new(2, 1)], Some('x'), Colour::Red);
+        renderer.add_layer([IVec2::new(2, 1)], Some('y'), Colour::Red);
+
+        assert_eq!(renderer.render(), "\x1b[32mO\x1b[31mO\x1b[0m.\n..\x1b[31my\x1b[0m\n");
+    }
+
+    #[test]
+    fn direction_order_test() 
+    {
+        use Direction::*;
+
+        assert_eq!(Direction::iter_4().collect::<Vec<_>>(), vec![North, East, South, West]);
+        assert_eq!(Direction::iter_8().collect::<Vec<_>>(), vec![North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest]);
+
+        let diagonals: Vec<Direction> = Direction::iter_8().filter(|dir| dir.is_diagonal()).collect();
+        assert_eq!(diagonals, vec![NorthEast, SouthEast, SouthWest, NorthWest]);
+        assert!(Direction::iter_4().all(|dir| !dir.is_diagonal()));
+    }
+
+    #[test]
+    fn direction_rotate_test() 
+    {
+        use Direction::*;
+
+        assert_eq!(North.rot_right(), East);
+        assert_eq!(North.rot_left(), West);
+        assert_eq!(West.rot_right(), North);
+        assert_eq!(North.rot_left_45(), NorthWest);
+        assert_eq!(NorthWest.rot_right_45(), North);
+        assert_eq!(SouthEast.rot_right_45(), South);
+        assert_eq!(East.rotate(-3), NorthWest);
+        assert_eq!(East.rotate(11), SouthWest);
+        assert_eq!(East.rotate(-16), East);
+
+        assert_eq!(North.invert(), South);
+        assert_eq!(NorthEast.invert(), SouthWest);
+        for dir in Direction::iter_8()
+        {
+            assert_eq!(dir.invert().invert(), dir);
+            assert_eq!(dir.invert().to_vec(), -dir.to_vec());
+            assert_eq!(dir.rot_right_45().rot_left_45(), dir);
+            assert_eq!(dir.rotate(8), dir);
+        }
+    }
+
+    #[test]
+    fn direction_vec_and_text_test() 
+    {
+        use Direction::*;
+
+        // North is up, so y goes down.
+        assert_eq!(North.to_vec(), IVec2::new(0, -1));
+        assert_eq!(SouthWest.add_to(IVec2::new(3, 3)), IVec2::new(2, 4));
+        for dir in Direction::iter_8()
+        {
+            assert_eq!(Direction::from_vec(dir.to_vec()), Some(dir));
+            assert_eq!(Direction::from_compass(&dir.to_string()), Some(dir));
+        }
+        assert_eq!(Direction::from_vec(IVec2::ZERO), None);
+        assert_eq!(Direction::from_vec(IVec2::new(2, 0)), None);
+
+        assert_eq!(Direction::from_compass("ne"), Some(NorthEast));
+        assert_eq!(Direction::from_compass("Sw"), Some(SouthWest));
+        assert_eq!(Direction::from_compass("w"), Some(West));
+        for text in ["", "north", "NNE", "EN", " N"]
+        {
+            assert_eq!(Direction::from_compass(text), None, "{text:?}");
+        }
+
+        assert_eq!(NorthWest.to_string(), "NW");
+        assert_eq!(format!("{South} {East}"), "S E");
+        assert_eq!(Direction::from('v'), Some(South));
+        assert_eq!(Direction::from('x'), None);
+    }
+
+    // a b c
+    //  d e f
+    // g h i
+    const HEX_STR: &str = "a b c\n d e f\ng h i";
+
+    #[test]
+    fn hex_grid_positions_test() 
+    {
+        let grid = HexGrid::parse(HEX_STR).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+
+        // Row 1 is pushed right so d is south east of a, and row 2 starts one q further west.
+        let cells: Vec<(IVec2, char)> = grid.cells().map(|(pos, c)| (pos, *c)).collect();
+        assert_eq!(cells, vec![(IVec2::new(0, 0), 'a'), (IVec2::new(1, 0), 'b'), (IVec2::new(2, 0), 'c'),
+                               (IVec2::new(0, 1), 'd'), (IVec2::new(1, 1), 'e'), (IVec2::new(2, 1), 'f'),
+                               (IVec2::new(-1, 2), 'g'), (IVec2::new(0, 2), 'h'), (IVec2::new(1, 2), 'i')]);
+        assert_eq!(HexDir::SouthEast.add_to(IVec2::new(0, 0)), IVec2::new(0, 1));
+        assert_eq!(HexDir::SouthWest.add_to(IVec2::new(0, 1)), IVec2::new(-1, 2));
+
+        // Every position maps back to the cell it came from.
+        for (pos, c) in cells
+        {
+            assert!(grid.contains(pos));
+            assert_eq!(grid[pos], c);
+        }
+
+        for pos in [IVec2::new(-1, 0), IVec2::new(3, 0), IVec2::new(-1, 1), IVec2::new(3, 1), IVec2::new(-2, 2), IVec2::new(2, 2), IVec2::new(0, 3), IVec2::new(0, -1)]
+        {
+            assert!(!grid.contains(pos), "{pos}");
+            assert_eq!(grid.get(pos), None);
+        }
+    }
+
+    #[test]
+    fn hex_grid_parse_test() 
+    {
+        let grid = HexGrid::parse(HEX_STR).unwrap();
+
+        // Indentation shared by every row is dropped.
+        assert_eq!(HexGrid::parse("   a b c\n    d e f\n   g h i"), Ok(grid.clone()));
+
+        assert_eq!(grid.to_string(), "a b c\n d e f\ng h i\n");
+        assert_eq!(HexGrid::parse(&grid.to_string()), Ok(grid));
+
+        let result = HexGrid::parse("a b c\n d e\ng h i");
+        assert_eq!(result, Err(ParseError::new(2, 5, "row has 2 hexes but the first row has 3")));
+
+        let result = HexGrid::parse("a b c\n d ef\ng h i");
+        assert_eq!(result, Err(ParseError::new(2, 5, "expected a space between hexes, found 'f'")));
+
+        // The odd row isn't indented, so its first hex is where a space should be.
+        let result = HexGrid::parse("a b c\nd e f");
+        assert_eq!(result, Err(ParseError::new(2, 1, "expected a space between hexes, found 'd'")));
+    }
+
+    #[test]
+    fn hex_distance_test() 
+    {
+        for dir in HexDir::iter()
+        {
+            assert_eq!(hex_distance(IVec2::ZERO, dir.to_vec()), 1, "{dir}");
+        }
+
+        assert_eq!(hex_distance(IVec2::new(0, 0), IVec2::new(2, -1)), 2);
+        assert_eq!(hex_distance(IVec2::new(0, 0), IVec2::new(3, 3)), 6);
+        assert_eq!(hex_distance(IVec2::new(3, 3), IVec2::new(0, 0)), 6);
+        assert_eq!(hex_distance(IVec2::new(0, 2), IVec2::new(2, 0)), 2);
+        assert_eq!(hex_distance(IVec2::new(-1, 2), IVec2::new(2, 0)), 3);
+    }
+
+    #[test]
+    fn hex_grid_neighbours_test() 
+    {
+        let grid = HexGrid::parse(HEX_STR).unwrap();
+        let neighbours_of = |c: char| -> String { grid.neighbours(grid.find_all(c)[0]).map(|pos| grid[pos]).collect() };
+
+        // Clockwise from East, leaving out the ones off the edge.
+        assert_eq!(neighbours_of('e'), "fihdbc");
+        assert_eq!(neighbours_of('a'), "bd");
+        assert_eq!(neighbours_of('c'), "feb");
+        assert_eq!(neighbours_of('d'), "ehgab");
+        assert_eq!(neighbours_of('f'), "iec");
+        assert_eq!(neighbours_of('g'), "hd");
+        assert_eq!(neighbours_of('i'), "hef");
+    }
+
+    #[test]
+    fn grid_bfs_test() 
+    {
+        let grid = CharGrid::from(".#..\n.#.#\n...#");
+        let distances = grid.bfs_distances(IVec2::new(0, 0), |c| *c == '.');
+
+        assert_eq!(distances[IVec2::new(3, 0)], Some(7));
+        assert_eq!(distances[IVec2::new(1, 0)], None);
+        assert_eq!(distances.cells().filter(|(_, dist)| dist.is_some()).count(), 8);
+    }
+
+    #[test]
+    fn hex_grid_bfs_test() 
+    {
+        let grid = HexGrid::parse(". # .\n . # .\n. . .").unwrap();
+        let distances = grid.bfs_distances(IVec2::new(0, 0), |c| *c == '.');
+
+        assert_eq!(distances[IVec2::new(0, 1)], Some(1));
+        assert_eq!(distances[IVec2::new(2, 0)], Some(5));
+        assert_eq!(distances[IVec2::new(1, 0)], None);
+        assert_eq!(distances.cells().filter(|(_, dist)| dist.is_some()).count(), 7);
+    }
+
+    #[test]
+    fn voxel_grid_layers_test() 
+    {
+        let grid = VoxelGrid::parse("ab\ncd\n\n\nef\ngh\n").unwrap();
+        assert_eq!(grid.m_size, IVec3::new(2, 2, 2));
+        assert_eq!(grid[IVec3::new(1, 0, 1)], 'f');
+        assert_eq!(rows_of(&grid.layer(1)), vec!["ef", "gh"]);
+
+        let result = VoxelGrid::parse("ab\ncd\n\nabc\ndef");
+        assert_eq!(result, Err(ParseError::new(4, 1, "layer is 3x2 but the first layer is 2x2")));
+
+        // Errors inside a layer point at the line in the whole input.
+        let result = VoxelGrid::parse("ab\ncd\n\nef\ng");
+        assert_eq!(result.map_err(|err| (err.m_line, err.m_column)), Err((5, 2)));
+        let result = VoxelGrid::from_layers("..\n..\n\n..\n.x", |c| if c == '.' { Ok(0) } else { Err(format!("unexpected '{c}'")) });
+        assert_eq!(result, Err(ParseError::new(5, 2, "unexpected 'x'")));
+    }
+
+    #[test]
+    fn voxel_grid_bfs_test() 
+    {
+        // The middle layer only lets through at one corner.
+        let grid = VoxelGrid::parse("...\n...\n...\n\n###\n###\n##.\n\n...\n...\n...").unwrap();
+        let distances = grid.bfs_distances(IVec3::new(0, 0, 0), |c| *c == '.');
+
+        assert_eq!(distances[IVec3::new(2, 2, 0)], Some(4));
+        assert_eq!(distances[IVec3::new(2, 2, 2)], Some(6));
+        assert_eq!(distances[IVec3::new(0, 0, 2)], Some(10));
+        assert_eq!(distances[IVec3::new(1, 1, 1)], None);
+        assert_eq!(grid.neighbours_6(IVec3::new(0, 0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_26(IVec3::new(1, 1, 1)).count(), 26);
+    }
+
+    // Directed graph with one char per state.
+    fn graph(edges: &[(char, char, u64)]) -> impl SearchSpace<State = char> + '_
+    {
+        return SuccessorFn::from(move |state| edges.iter().filter(move |(from, _, _)| *from == state).map(|(_, to, cost)| (*to, *cost)));
+    }
+
+    fn sorted(states: &[char]) -> Vec<char>
+    {
+        let mut states = states.to_vec();
+        states.sort();
+        return states;
+    }
+
+    #[test]
+    fn search_tied_goals_test() 
+    {
+        let edges = [('s', 'a', 1), ('s', 'b', 1), ('a', 'x', 1), ('b', 'x', 1), ('b', 'y', 1), ('s', 'z', 5)];
+        let space = graph(&edges);
+        let result = dijkstra_all_paths(&space, 's', |state| "xyz".contains(state));
+
+        assert_eq!(sorted(result.goals()), vec!['x', 'y']);
+        assert_eq!(result.goal_cost(), Some(2));
+        assert_eq!(sorted(result.predecessors('x')), vec!['a', 'b']);
+        assert_eq!(result.predecessors('y'), &['b']);
+
+        let mut on_paths: Vec<char> = result.states_on_best_paths(result.goals()).into_iter().collect();
+        on_paths.sort();
+        assert_eq!(on_paths, vec!['a', 'b', 's', 'x', 'y']);
+    }
+
+    #[test]
+    fn search_unreachable_goal_test() 
+    {
+        let edges = [('s', 'a', 1), ('a', 's', 1), ('g', 's', 1)];
+        let space = graph(&edges);
+
+        for result in [bfs(&space, 's', |state| state == 'g'), dijkstra(&space, 's', |state| state == 'g'), astar(&space, 's', |state| state == 'g')]
+        {
+            assert_eq!(result.goal(), None);
+            assert_eq!(result.goal_cost(), None);
+            assert_eq!(result.path_to('g'), None);
+            assert_eq!(result.cost('a'), Some(1));
+        }
+    }
+
+    #[test]
+    fn search_astar_matches_dijkstra_test() 
+    {
+        let grid = CharGrid::from(r"..#.....
+.##.###.
+....#...
+.####.#.
+......#.");
+        let start = IVec2::new(0, 0);
+
+        for end in grid.positions_where(|c| *c == '.')
+        {
+            let mut walk = GridWalk::from(&grid, |c| *c == '.');
+            let expected = dijkstra(&walk, start, |pos| pos == end);
+            walk.set_goal(end);
+            let result = astar(&walk, start, |pos| pos == end);
+
+            assert_eq!(result.goal_cost(), expected.goal_cost(), "{end}");
+            assert_eq!(result.goal_cost().map(|cost| cost as u32), grid.bfs_distances(start, |c| *c == '.')[end], "{end}");
+
+            let path = result.path_to(end).unwrap();
+            assert_eq!(path.len() as u64, result.goal_cost().unwrap() + 1);
+            assert!(path.windows(2).all(|step| (step[1] - step[0]).abs().element_sum() == 1), "{end}");
+        }
+    }
+
+    #[test]
+    fn search_zero_cost_test() 
+    {
+        // 'b' costs the same as the goal, so either of them can come off the heap first.
+        let edges = [('s', 'g', 2), ('s', 'b', 2), ('b', 'g', 0), ('g', 'c', 0)];
+        let space = graph(&edges);
+        let result = dijkstra_all_paths(&space, 's', |state| state == 'g');
+
+        assert_eq!(result.goal_cost(), Some(2));
+        assert_eq!(sorted(result.predecessors('g')), vec!['b', 's']);
+        assert_eq!(result.states_on_best_paths(&['g']).len(), 3);
+
+        let result = dijkstra_all_paths(&space, 's', |_| false);
+        assert_eq!(result.cost('c'), Some(2));
+        assert_eq!(sorted(result.predecessors('g')), vec!['b', 's']);
+        assert_eq!(result.predecessors('c'), &['g']);
+    }
+
+    #[test]
+    fn separated_numbers_test() 
+    {
+        let grid = IntGrid::from_numbers("8, 9, 0, 1\n-7, 8, 1, 2\n").unwrap();
+        assert_eq!((grid.m_width, grid.m_height, grid.at(0, 1)), (4, 2, Some(-7)));
+
+        let grid = IntGrid::from_numbers("10,-20,30\n-40,50,-600").unwrap();
+        assert_eq!(grid.rows().map(|row| row.to_vec()).collect::<Vec<_>>(), vec![vec![10, -20, 30], vec![-40, 50, -600]]);
+
+        let grid = IntGrid::from_numbers_with_placeholder(". -3 .\n12 . -1", ".", i32::MIN).unwrap();
+        assert_eq!(grid.rows().map(|row| row.to_vec()).collect::<Vec<_>>(), vec![vec![i32::MIN, -3, i32::MIN], vec![12, i32::MIN, -1]]);
+
+        // Only the exact placeholder is missing, anything else still has to be a number.
+        let result = IntGrid::from_numbers_with_placeholder(". 1\n.. 2", ".", -1);
+        assert_eq!(result.map_err(|err| (err.m_line, err.m_column)), Err((2, 1)));
+
+        let result = IntGrid::from_numbers("1 2 3\n4 5\n");
+        assert_eq!(result.map_err(|err| (err.m_line, err.m_column)), Err((2, 4)));
+
+        let result = IntGrid::from_numbers("1,2\n3,x");
+        assert_eq!(result.map_err(|err| (err.m_line, err.m_column)), Err((2, 3)));
+    }
+
+    #[test]
+    fn byte_grid_test() 
+    {
+        let grid = ByteGrid::parse("ab\n#.").unwrap();
+        assert_eq!(grid.as_slice(), b"ab#.");
+        assert_eq!(ByteGrid::parse("ab\n#\u{e9}"), Err(ParseError::new(2, 2, "expected an ASCII character, found '\u{e9}'")));
+
+        let chars = CharGrid::from("a\u{e9}\n#.");
+        assert_eq!(rows_of(&ByteGrid::from_char_grid(&chars).to_char_grid()), vec!["a?", "#."]);
+
+        let padded = grid.padded(b'~', 2);
+        assert_eq!((padded.m_width, padded.m_height), (6, 6));
+        assert_eq!(rows_of(&padded.to_char_grid()), vec!["~~~~~~", "~~~~~~", "~~ab~~", "~~#.~~", "~~~~~~", "~~~~~~"]);
+        assert_eq!(grid.padded(b'~', 0), grid);
+    }
+
+    #[test]
+    fn grid_flat_index_test() 
+    {
+        let grid = ByteGrid::from_char_grid(&CharGrid::from(WIDE_STR));
+
+        for pos in grid.positions()
+        {
+            assert_eq!(grid.position_of(grid.flat_index(pos)), pos);
+        }
+        assert_eq!(grid.position_of(7), IVec2::new(3, 1));
+        assert_eq!(grid.as_slice()[grid.flat_index(IVec2::new(3, 1))], b'h');
+
+        // Same order as neighbours_4: up, right, down, left.
+        let middle = IVec2::new(1, 1);
+        let stepped: Vec<IVec2> = grid.flat_offsets_4().iter().map(|step| grid.position_of(grid.flat_index(middle).wrapping_add_signed(*step))).collect();
+        assert_eq!(stepped, grid.neighbours_4(middle).collect::<Vec<_>>());
+        assert_eq!(grid.flat_offsets_4(), [-4, 1, 4, -1]);
+    }
+
+    #[test]
+    fn grid_image_ppm_test() 
+    {
+        let path = env::temp_dir().join(format!("aoc_core_image_{}.ppm", std::process::id()));
+        let palette = Palette::from(&[('a', [1, 2, 3])], [9, 9, 9]);
+        CharGrid::from("ab\nba").write_image(&path, &palette, 3).unwrap();
+        let data = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let header = b"P6\n6 6\n255\n";
+        assert_eq!(&data[..header.len()], header);
+        let pixels = &data[header.len()..];
+        assert_eq!(pixels.len(), 6 * 6 * 3);
+
+        // Top left 3x3 square is 'a', the next one along is the fallback for 'b'.
+        assert_eq!(&pixels[0..9], &[1, 2, 3, 1, 2, 3, 1, 2, 3]);
+        assert_eq!(&pixels[9..12], &[9, 9, 9]);
+        let row_2 = 2 * 6 * 3;
+        assert_eq!(&pixels[row_2..row_2 + 3], &[1, 2, 3]);
+        let row_3 = 3 * 6 * 3;
+        assert_eq!(&pixels[row_3..row_3 + 3], &[9, 9, 9]);
+    }
+
+    #[test]
+    fn frame_writer_test() 
+    {
+        const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+        let dir = env::temp_dir().join(format!("aoc_core_frames_{}", std::process::id()));
+        let mut frames = FrameWriter::from(&dir, Palette::default(), 2).unwrap();
+        frames.write_frame(&CharGrid::from("#.\n.@")).unwrap();
+        frames.write_frame(&CharGrid::from("#.\n@.")).unwrap();
+
+        let written: Vec<Vec<u8>> = ["frame_00000.png", "frame_00001.png", "frame_00002.png"].iter().map(|name| fs::read(dir.join(name)).unwrap_or_default()).collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(frames.frames_written(), 2);
+        assert!(written[0].starts_with(PNG_SIGNATURE));
+        assert!(written[1].starts_with(PNG_SIGNATURE));
+        assert_ne!(written[0], written[1]);
+        assert!(written[2].is_empty());
+
+        // Width and height are the first fields of the IHDR chunk, after the signature.
+        assert_eq!(&written[0][16..24], &[0, 0, 0, 4, 0, 0, 0, 4]);
+    }
+
+    #[test]
+    fn grid_heatmap_test() 
+    {
+        let path = env::temp_dir().join(format!("aoc_core_heatmap_{}.ppm", std::process::id()));
+        let grid = Grid::from_fn(3, 1, |pos| [-1, 0, 4][pos.x as usize]);
+        grid.write_heatmap(&path, 1).unwrap();
+        let data = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let header = b"P6\n3 1\n255\n";
+        assert_eq!(&data[..header.len()], header);
+        assert_eq!(&data[header.len()..], &[0, 0, 0, 0, 0, 255, 255, 0, 0]);
+    }
+}
\ No newline at end of file
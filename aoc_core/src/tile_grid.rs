@@ -0,0 +1,93 @@
+use glam::IVec2;
+
+use crate::char_grid::CharGrid;
+use crate::grid::Grid;
+use crate::parse_error::ParseError;
+
+// A cell type that is drawn as one char in the puzzle input, usually an enum.
+pub trait Tile: Sized
+{
+    // None for a char that isn't a tile of this type.
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+// Where marker chars like S, E or @ were found while parsing, in reading order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Markers
+{
+    m_found: Vec<(IVec2, char)>,
+}
+
+impl Markers
+{
+    pub fn positions_of(&self, marker: char) -> impl Iterator<Item = IVec2> + '_
+    {
+        return self.m_found.iter().filter(move |(_, c)| *c == marker).map(|(pos, _)| *pos);
+    }
+
+    // Exactly one of any of `markers`, and which one it was. `what` names it in the error.
+    pub fn expect_unique(&self, markers: &[char], what: &str) -> Result<(IVec2, char), ParseError>
+    {
+        let mut matches = self.m_found.iter().filter(|(_, c)| markers.contains(c));
+
+        let Some(first) = matches.next() else
+        {
+            return Err(ParseError::new(1, 1, format!("no {what} in the grid")));
+        };
+
+        if let Some((second, _)) = matches.next()
+        {
+            return Err(ParseError::new(second.y as usize + 1, second.x as usize + 1, format!("found a second {what}, the first is at {}:{}", first.0.y + 1, first.0.x + 1)));
+        }
+
+        return Ok(*first);
+    }
+}
+
+impl<T: Tile> Grid<T>
+{
+    // A char that isn't a tile is an error pointing at it.
+    pub fn parse_tiles(input: &str) -> Result<Self, ParseError>
+    {
+        return Self::from_parser(input, parse_tile);
+    }
+
+    // As parse_tiles, but every char in `markers` is recorded and replaced with `under`,
+    // e.g. the floor that a start marker is drawn on.
+    pub fn parse_tiles_with_markers(input: &str, markers: &[char], under: T) -> Result<(Self, Markers), ParseError>
+        where T: Clone
+    {
+        let mut index = 0;
+        let mut found: Vec<(usize, char)> = Vec::new();
+
+        let grid = Self::from_parser(input, |c|
+        {
+            index += 1;
+            if markers.contains(&c)
+            {
+                found.push((index - 1, c));
+                return Ok(under.clone());
+            }
+
+            return parse_tile(c);
+        })?;
+
+        let markers = Markers
+        {
+            m_found: found.into_iter().map(|(index, c)| (grid.position_of(index), c)).collect(),
+        };
+
+        return Ok((grid, markers));
+    }
+
+    pub fn to_char_grid(&self) -> CharGrid
+    {
+        return self.map(T::to_char);
+    }
+}
+
+fn parse_tile<T: Tile>(c: char) -> Result<T, String>
+{
+    return T::from_char(c).ok_or(format!("unexpected character '{c}'"));
+}
\ No newline at end of file
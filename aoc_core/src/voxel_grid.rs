@@ -0,0 +1,215 @@
+use std::ops::{Index, IndexMut};
+
+use glam::{IVec2, IVec3};
+
+use crate::grid::Grid;
+use crate::parse_error::{split_sections, ParseError};
+use crate::search::{bfs, SuccessorFn};
+
+// Faces of a cube.
+const NEIGHBOURS_6: [IVec3; 6] = [IVec3::NEG_X, IVec3::X, IVec3::NEG_Y, IVec3::Y, IVec3::NEG_Z, IVec3::Z];
+
+// Box of cells stored layer by layer, then row by row. z picks the layer, x and y are the same as
+// in a Grid.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoxelGrid<T>
+{
+    m_cells: Vec<T>,
+    pub m_size: IVec3,
+}
+
+impl<T> VoxelGrid<T>
+{
+    // Each layer is a grid of characters like Grid::from_parser reads, layers are separated by
+    // blank lines and all have to be the same size.
+    pub fn from_layers(input: &str, mut parse_cell: impl FnMut(char) -> Result<T, String>) -> Result<Self, ParseError>
+    {
+        let mut cells: Vec<T> = Vec::new();
+        let mut layer_size: Option<IVec2> = None;
+        let mut depth = 0;
+
+        for (first_line, section) in split_sections(input)
+        {
+            let layer = Grid::from_parser(section, &mut parse_cell).map_err(|err| err.offset_lines(first_line))?;
+            let size = IVec2::new(layer.m_width, layer.m_height);
+            let expected = *layer_size.get_or_insert(size);
+            if size != expected
+            {
+                return Err(ParseError::new(first_line, 1, format!("layer is {}x{} but the first layer is {}x{}", size.x, size.y, expected.x, expected.y)));
+            }
+
+            cells.extend(layer.into_vec());
+            depth += 1;
+        }
+
+        let size = layer_size.unwrap_or(IVec2::ZERO);
+        let this = Self
+        {
+            m_cells: cells,
+            m_size: IVec3::new(size.x, size.y, depth),
+        };
+
+        return Ok(this);
+    }
+
+    pub fn from_value(value: T, size: IVec3) -> Self
+        where T: Clone
+    {
+        let this = Self
+        {
+            m_cells: vec![value; (size.x * size.y * size.z) as usize],
+            m_size: size,
+        };
+
+        return this;
+    }
+
+    // `make_cell` is called for every position in the same order as positions().
+    pub fn from_fn(size: IVec3, make_cell: impl FnMut(IVec3) -> T) -> Self
+    {
+        return Self
+        {
+            m_cells: positions_in(size).map(make_cell).collect(),
+            m_size: size,
+        };
+    }
+
+    pub fn contains(&self, pos: IVec3) -> bool
+    {
+        return pos.cmpge(IVec3::ZERO).all() && pos.cmplt(self.m_size).all();
+    }
+
+    fn index_of(&self, pos: IVec3) -> Option<usize>
+    {
+        if !self.contains(pos)
+        {
+            return None;
+        }
+
+        return Some((pos.x + (pos.y + pos.z * self.m_size.y) * self.m_size.x) as usize);
+    }
+
+    pub fn get(&self, pos: IVec3) -> Option<&T>
+    {
+        return self.index_of(pos).map(|idx| &self.m_cells[idx]);
+    }
+
+    pub fn get_mut(&mut self, pos: IVec3) -> Option<&mut T>
+    {
+        return self.index_of(pos).map(|idx| &mut self.m_cells[idx]);
+    }
+
+    pub fn map<U>(&self, convert: impl FnMut(&T) -> U) -> VoxelGrid<U>
+    {
+        return VoxelGrid
+        {
+            m_cells: self.m_cells.iter().map(convert).collect(),
+            m_size: self.m_size,
+        };
+    }
+
+    // A copy of layer `z`.
+    pub fn layer(&self, z: i32) -> Grid<T>
+        where T: Clone
+    {
+        assert!((0..self.m_size.z).contains(&z), "layer {z} is outside the {} grid", self.m_size);
+        return Grid::from_fn(self.m_size.x, self.m_size.y, |pos| self[pos.extend(z)].clone());
+    }
+
+    // Every position, layer by layer then row by row.
+    pub fn positions(&self) -> impl Iterator<Item = IVec3>
+    {
+        return positions_in(self.m_size);
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (IVec3, &T)> + '_
+    {
+        return self.positions().zip(self.m_cells.iter());
+    }
+
+    pub fn positions_where<'a>(&'a self, predicate: impl Fn(&T) -> bool + 'a) -> impl Iterator<Item = IVec3> + 'a
+    {
+        return self.cells().filter(move |(_, cell)| predicate(cell)).map(|(pos, _)| pos);
+    }
+
+    pub fn find_all(&self, item: T) -> Vec<IVec3>
+        where T: PartialEq
+    {
+        return self.positions_where(|cell| *cell == item).collect();
+    }
+
+    pub fn count(&self, item: T) -> usize
+        where T: PartialEq
+    {
+        return self.m_cells.iter().filter(|cell| **cell == item).count();
+    }
+
+    // The cells sharing a face with `pos`, leaving out any off the edge.
+    pub fn neighbours_6(&self, pos: IVec3) -> impl Iterator<Item = IVec3> + '_
+    {
+        return NEIGHBOURS_6.into_iter().map(move |offset| pos + offset).filter(|next| self.contains(*next));
+    }
+
+    // The cells sharing a face, edge or corner with `pos`.
+    pub fn neighbours_26(&self, pos: IVec3) -> impl Iterator<Item = IVec3> + '_
+    {
+        return positions_in(IVec3::splat(3))
+            .map(|offset| offset - IVec3::ONE)
+            .filter(|offset| *offset != IVec3::ZERO)
+            .map(move |offset| pos + offset)
+            .filter(|next| self.contains(*next));
+    }
+
+    // Fewest face to face steps from `start` to every cell, only walking on cells that are
+    // `passable`. None where it can't be reached.
+    pub fn bfs_distances(&self, start: IVec3, passable: impl Fn(&T) -> bool) -> VoxelGrid<Option<u32>>
+    {
+        let passable = &passable;
+        let walk = SuccessorFn::from(move |pos| self.neighbours_6(pos).filter(move |next| passable(&self[*next])).map(|next| (next, 1)));
+        let reached = bfs(&walk, start, |_| false);
+
+        return VoxelGrid::from_fn(self.m_size, |pos| reached.cost(pos).map(|cost| cost as u32));
+    }
+}
+
+impl VoxelGrid<char>
+{
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    {
+        return Self::from_layers(input, Ok);
+    }
+}
+
+// Panics outside the grid, use get() when the position might be off the edge.
+impl<T> Index<IVec3> for VoxelGrid<T>
+{
+    type Output = T;
+
+    fn index(&self, pos: IVec3) -> &T
+    {
+        let Some(idx) = self.index_of(pos) else
+        {
+            panic!("{pos} is outside the {} grid", self.m_size);
+        };
+
+        return &self.m_cells[idx];
+    }
+}
+
+impl<T> IndexMut<IVec3> for VoxelGrid<T>
+{
+    fn index_mut(&mut self, pos: IVec3) -> &mut T
+    {
+        let Some(idx) = self.index_of(pos) else
+        {
+            panic!("{pos} is outside the {} grid", self.m_size);
+        };
+
+        return &mut self.m_cells[idx];
+    }
+}
+
+fn positions_in(size: IVec3) -> impl Iterator<Item = IVec3>
+{
+    return (0..size.z).flat_map(move |z| (0..size.y).flat_map(move |y| (0..size.x).map(move |x| IVec3::new(x, y, z))));
+}
\ No newline at end of file
@@ -0,0 +1,99 @@
+use aoc_core::parse_error::*;
+
+#[derive(Clone)]
+pub struct ListPair
+{
+    pub list1 : Vec<i32>,
+    pub list2 : Vec<i32>
+}
+
+impl ListPair
+{
+    fn new() -> Self 
+    {
+        Self 
+        {
+            list1: Vec::new(),
+            list2: Vec::new(),
+        }
+    }
+}
+
+pub fn find_total_dist_str(input: &String) -> Result<i32, ParseError>
+{
+    return Ok(find_total_dist(&read_list_pairs(input)?));
+}
+
+pub fn get_similarity_dist_str(input: &String) -> Result<i32, ParseError>
+{
+    return Ok(get_similarity_dist(&read_list_pairs(input)?));
+}
+
+pub fn read_list_pairs(input: &String) -> Result<ListPair, ParseError>
+{
+    let lines_split : Vec<(i32, i32)> = numbered_lines(input)
+                                            .map(|(line_num, line)| parse_to_tuple(line_num, line))
+                                            .collect::<Result<_, _>>()?;
+
+    let mut result : ListPair = ListPair::new();
+
+    for line_split in lines_split.iter()
+    {
+        result.list1.push(line_split.0);
+        result.list2.push(line_split.1);
+    }
+
+    return Ok(result);
+}
+
+fn parse_to_tuple(line_num: usize, input: &str) -> Result<(i32, i32), ParseError>
+{
+    // Split the string by whitespace
+    let parts: Vec<&str> = input.split_whitespace().collect();
+
+    if parts.len() != 2
+    {
+        return Err(ParseError::new(line_num, 1, format!("expected two numbers, found {} items", parts.len())));
+    }
+
+    // Try to parse the two parts as i32
+    let first = parse_token(line_num, input, parts[0])?;
+    let second = parse_token(line_num, input, parts[1])?;
+
+    return Ok((first, second));
+}
+
+pub fn find_total_dist(lists : &ListPair) -> i32
+{
+    let mut lists = lists.clone();
+    lists.list1.sort();
+    lists.list2.sort();
+
+    let mut total_dist : i32 = 0;
+
+    for i in 0..lists.list1.len()
+    {
+        total_dist += (lists.list1[i] - lists.list2[i]).abs();
+    }
+    
+    return total_dist;
+}
+
+pub fn get_similarity_dist(lists : &ListPair) -> i32
+{
+    let mut lists = lists.clone();
+    lists.list1.sort();
+    lists.list2.sort();
+
+    let mut total_sim : i32 = 0;
+
+    for i in 0..lists.list1.len()
+    {
+        let val = lists.list1[i];
+        let num_occ : i32 = (lists.list2.iter().filter(|&n| *n == val).count()) as i32;
+
+        total_sim += val * num_occ;
+    }
+    
+    return total_sim;
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem::{self, ListPair};
+
+pub struct Day1;
+
+impl Solution for Day1
+{
+    type Params = NoParams;
+    type Parsed1 = ListPair;
+    type Parsed2 = ListPair;
+
+    const DAY: u32 = 1;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return problem::read_list_pairs(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::find_total_dist(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return problem::read_list_pairs(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::get_similarity_dist(parsed));
+    }
+}
\ No newline at end of file
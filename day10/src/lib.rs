@@ -0,0 +1,5 @@
+#![allow(dead_code)]
+
+mod tests;
+pub mod problem;
+pub mod solution;
\ No newline at end of file
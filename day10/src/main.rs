@@ -1,26 +1,8 @@
-#![allow(dead_code)]
+use aoc_core::solution::run_main;
 
-mod tests;
-mod problem;
-mod char_grid;
-mod int_grid;
-
-use std::fs;
-
-fn read_all_lines(path : String) -> String
-{
-    let contents : String = fs::read_to_string(path).expect("Should have been able to read the file");
-    return contents;
-}
+use day10::solution::Day10;
 
 fn main()
 {
-    let file_path : String = String::from("./input.txt");
-    let file_contents = read_all_lines(file_path);
-
-    let part1 = problem::total_trail_score(&file_contents);
-    let part2 = problem::total_trail_score_part2(&file_contents);
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    run_main::<Day10>();
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+use aoc_core::int_grid::IntGrid;
+
+use crate::problem;
+
+pub struct Day10;
+
+impl Solution for Day10
+{
+    type Params = NoParams;
+    type Parsed1 = IntGrid;
+    type Parsed2 = IntGrid;
+
+    const DAY: u32 = 10;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return IntGrid::from(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::sum_trail_scores(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return IntGrid::from(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::sum_trail_ratings(parsed));
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests 
 {
+    use aoc_core::int_grid::IntGrid;
     use crate::problem;
 
     const TEST_STR: &str = r"89010123
@@ -16,13 +17,27 @@ mod tests
     fn part_1_test() 
     {
         let result = problem::total_trail_score(&String::from(TEST_STR));
-        assert_eq!(result, 36);
+        assert_eq!(result, Ok(36));
     }
 
     #[test]
     fn part_2_test() 
     {
          let result = problem::total_trail_score_part2(&String::from(TEST_STR));
-         assert_eq!(result, 81);
+         assert_eq!(result, Ok(81));
+    }
+
+    #[test]
+    fn placeholder_trail_test() 
+    {
+        // Missing cells are never on a trail.
+        let grid = IntGrid::from_numbers_with_placeholder(r". . 9 0 . . 9
+. . . 1 . 9 8
+. . . 2 . . 7
+6 5 4 3 4 5 6
+7 6 5 . 9 8 7
+8 7 6 . . . .
+9 8 7 . . . .", ".", -1).unwrap();
+        assert_eq!(problem::sum_trail_scores(&grid), 4);
     }
 }
\ No newline at end of file
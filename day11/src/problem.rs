@@ -1,9 +1,16 @@
-use std::{cmp, u128};
+use std::cmp;
+
+use aoc_core::parse_error::*;
 
 // Problem
-pub fn get_num_stones(input: &String, blinks: i32) -> usize
+pub fn get_num_stones(input: &String, blinks: i32) -> Result<usize, ParseError>
+{
+    return Ok(count_stones(&parse_to_vec(input)?, blinks));
+}
+
+pub fn count_stones(rocks: &Vec<u128>, blinks: i32) -> usize
 {
-    let mut rocks = parse_to_vec(input);
+    let mut rocks = rocks.clone();
 
     for _ in 0..blinks
     {
@@ -13,34 +20,35 @@ pub fn get_num_stones(input: &String, blinks: i32) -> usize
     return rocks.len();
 }
 
-pub fn get_num_stones_cheat_sheets(input: &String, blinks: usize) -> usize
+pub fn get_num_stones_cheat_sheets(input: &String, blinks: usize) -> Result<usize, ParseError>
+{
+    return Ok(count_stones_cheat_sheets(&parse_to_vec(input)?, blinks));
+}
+
+pub fn count_stones_cheat_sheets(rocks: &Vec<u128>, blinks: usize) -> usize
 {
     let cheat_sheet_blinks = blinks / 2;
     let cheat_sheet_max = 20;
 
     let cheat_sheets: Vec<Vec<usize>> = generate_cheat_sheets(cheat_sheet_blinks, cheat_sheet_max);
 
-    let mut rocks = parse_to_vec(input);
+    let mut rocks = rocks.clone();
 
     let mut cheated_steps : usize = 0;
 
-    dbg!(&cheat_sheets);
-
     for i in 0..blinks
     {
-        println!("Blink {i}...");
         rocks = do_step(rocks);
 
         let blinks_left = blinks - i - 1;
         if 0 < blinks_left && blinks_left <= cheat_sheet_blinks
         {
-            println!("Cheating with {blinks_left}...");
             // Apply cheat sheets
             let mut i = 0;
             while i < rocks.len()
             {
                 let rock = rocks[i];
-                if rock < cheat_sheet_max as u128
+                if rock < cheat_sheet_max
                 {
                     cheated_steps += cheat_sheets[rock as usize][blinks_left - 1];
                     rocks.swap_remove(i);
@@ -62,7 +70,6 @@ fn generate_cheat_sheets(blinks: usize, cheat_sheet_max: u128) -> Vec<Vec<usize>
 
     for i in 0..cheat_sheet_max
     {
-        println!("Generating sheet {i}...");
         result.push(generate_cheat_sheet(i, blinks));
     }
 
@@ -73,8 +80,7 @@ fn generate_cheat_sheets(blinks: usize, cheat_sheet_max: u128) -> Vec<Vec<usize>
 fn generate_cheat_sheet(rock: u128, blinks: usize) -> Vec<usize>
 {
     let mut result = Vec::new();
-    let mut rocks = Vec::new();
-    rocks.push(rock);
+    let mut rocks = vec![rock];
 
     for _ in 0..blinks
     {
@@ -87,8 +93,7 @@ fn generate_cheat_sheet(rock: u128, blinks: usize) -> Vec<usize>
 
 fn do_step(rocks: Vec<u128>) -> Vec<u128>
 {
-    let mut result = Vec::new();
-    result.reserve(rocks.len() * 2);
+    let mut result = Vec::with_capacity(rocks.len() * 2);
 
     for rock in rocks
     {
@@ -111,14 +116,19 @@ fn do_step(rocks: Vec<u128>) -> Vec<u128>
 }
 
 // Parse
-fn parse_to_vec(input: &String) -> Vec<u128>
+pub fn parse_to_vec(input: &String) -> Result<Vec<u128>, ParseError>
 {
-    let result = input.split(' ')
-                        .filter(|s| s.parse::<u128>().is_ok())
-                        .map(|s | s.parse::<u128>().unwrap())
-                        .collect();
+    let mut result = Vec::new();
 
-    return result;
+    for (line_num, line) in numbered_lines(input)
+    {
+        for stone in line.split_whitespace()
+        {
+            result.push(parse_token(line_num, line, stone)?);
+        }
+    }
+
+    return Ok(result);
 }
 
 // Math
@@ -127,7 +137,7 @@ fn count_digits(mut n: u128) -> i32
     let mut digits = 1;
     while n >= 10
     {
-        n = n / 10;
+        n /= 10;
         digits += 1;
     }
 
@@ -148,13 +158,13 @@ fn split_digits(n: u128) -> Option<(u128, u128)>
 
     for _ in 0..half_digits
     {
-        top = top / 10;
+        top /= 10;
     }
 
     let mut to_sub = top;
     for _ in 0..half_digits
     {
-        to_sub = to_sub * 10;
+        to_sub *= 10;
     }
 
     let bottom = n - to_sub;
@@ -0,0 +1,60 @@
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day11Params
+{
+    // Blinks for each part.
+    #[serde(rename = "part1_blinks")]
+    pub m_part1_blinks: i32,
+    #[serde(rename = "part2_blinks")]
+    pub m_part2_blinks: usize,
+}
+
+impl Default for Day11Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_part1_blinks: 25,
+            m_part2_blinks: 75,
+        };
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11
+{
+    type Params = Day11Params;
+    type Parsed1 = Vec<u128>;
+    type Parsed2 = Vec<u128>;
+
+    const DAY: u32 = 11;
+
+    fn parse_part1(input: &String, _params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return problem::parse_to_vec(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, params: &Self::Params) -> Answer
+    {
+        return Answer::from(problem::count_stones(parsed, params.m_part1_blinks));
+    }
+
+    fn parse_part2(input: &String, _params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return problem::parse_to_vec(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        return Answer::from(problem::count_stones_cheat_sheets(parsed, params.m_part2_blinks));
+    }
+}
\ No newline at end of file
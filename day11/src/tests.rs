@@ -9,27 +9,27 @@ mod tests
     fn part_1_test_small() 
     {
         let result = problem::get_num_stones(&String::from(TEST_STR), 6);
-        assert_eq!(result, 22);
+        assert_eq!(result, Ok(22));
     }
 
     #[test]
     fn part_1_test() 
     {
         let result = problem::get_num_stones(&String::from(TEST_STR), 25);
-        assert_eq!(result, 55312);
+        assert_eq!(result, Ok(55312));
     }
 
     #[test]
     fn part_2_test_small() 
     {
         let result = problem::get_num_stones_cheat_sheets(&String::from(TEST_STR), 6);
-        assert_eq!(result, 22);
+        assert_eq!(result, Ok(22));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = problem::get_num_stones_cheat_sheets(&String::from(TEST_STR), 25);
-        assert_eq!(result, 55312);
+        assert_eq!(result, Ok(55312));
     }
 }
\ No newline at end of file
@@ -1,9 +1,9 @@
 use std::collections::{HashMap, HashSet};
-use strum::IntoEnumIterator;
+use glam::IVec2;
 
-use crate::{char_grid::CharGrid, direction::Direction};
+use aoc_core::{char_grid::CharGrid, direction::Direction};
 
-type Point = (i32, i32);
+type Point = IVec2;
 
 pub fn total_fence_price(input: &String) -> i32
 {
@@ -22,30 +22,16 @@ pub fn total_fence_price_discounted(input: &String) -> i32
 // Analyse grid
 fn categorise_grid(grid: &CharGrid) -> HashMap<char, HashSet<Point>>
 {
-    let mut result : HashMap<char, HashSet<Point>> = HashMap::new();
-
-    for x in 0..grid.m_width
-    {
-        for y in 0..grid.m_height
-        {
-            let char_at = grid.at(x, y).unwrap();
-            if !result.contains_key(&char_at)
-            {
-                result.insert(char_at, HashSet::new());
-            }
-
-            let char_points: &mut HashSet<Point> = result.get_mut(&char_at).unwrap();
-            char_points.insert((x, y));
-        }
-    }
-
-    return result;
+    return grid.positions_by_char()
+               .into_iter()
+               .map(|(char_at, points)| (char_at, points.into_iter().collect()))
+               .collect();
 }
 
 // Normal fence price
-fn get_total_fence_prices(grid: &CharGrid) -> i32
+pub fn get_total_fence_prices(grid: &CharGrid) -> i32
 {
-    let char_to_points = categorise_grid(&grid);
+    let char_to_points = categorise_grid(grid);
 
     let mut total_price = 0;
     for char_points in char_to_points.iter()
@@ -60,7 +46,7 @@ fn get_total_fence_prices(grid: &CharGrid) -> i32
             for region_point in region_info.0.iter()
             {
                 let removed = all_char_points.remove(region_point);
-                assert!(removed, "Point ({}, {}) in region but not in char set?", region_point.0, region_point.1);
+                assert!(removed, "Point ({}, {}) in region but not in char set?", region_point.x, region_point.y);
             }
 
             // Add on region's price.
@@ -83,15 +69,15 @@ fn get_region_and_price(char_points : &HashSet<Point>, region_seed: Point) -> (H
 
     while let Some(search_point) = points_to_search.iter().next()
     {
-        let search_point = search_point.clone();
+        let search_point = *search_point;
         points_to_search.remove(&search_point);
 
         let new_point  = points_searched.insert(search_point);
 
-        assert!(new_point, "Searched {}, {} twice.", search_point.0, search_point.1);
+        assert!(new_point, "Searched {}, {} twice.", search_point.x, search_point.y);
 
         // Find neighbours
-        for dir in Direction::iter()
+        for dir in Direction::iter_4()
         {
             let neighbour = dir.add_to(search_point);
 
@@ -125,9 +111,9 @@ fn get_region_and_price(char_points : &HashSet<Point>, region_seed: Point) -> (H
 
 
 // Discount fence price
-fn get_total_fence_prices_discount(grid: &CharGrid) -> i32
+pub fn get_total_fence_prices_discount(grid: &CharGrid) -> i32
 {
-    let char_to_points = categorise_grid(&grid);
+    let char_to_points = categorise_grid(grid);
 
     let mut total_price = 0;
     for char_points in char_to_points.iter()
@@ -142,7 +128,7 @@ fn get_total_fence_prices_discount(grid: &CharGrid) -> i32
             for region_point in region_info.0.iter()
             {
                 let removed = all_char_points.remove(region_point);
-                assert!(removed, "Point ({}, {}) in region but not in char set?", region_point.0, region_point.1);
+                assert!(removed, "Point ({}, {}) in region but not in char set?", region_point.x, region_point.y);
             }
 
             // Add on region's price.
@@ -166,15 +152,15 @@ fn get_region_and_price_discount(char_points : &HashSet<Point>, region_seed: Poi
 
     while let Some(search_point) = points_to_search.iter().next()
     {
-        let search_point = search_point.clone();
+        let search_point = *search_point;
         points_to_search.remove(&search_point);
 
         let new_point  = points_searched.insert(search_point);
 
-        assert!(new_point, "Searched {}, {} twice.", search_point.0, search_point.1);
+        assert!(new_point, "Searched {}, {} twice.", search_point.x, search_point.y);
 
         // Find neighbours
-        for dir in Direction::iter()
+        for dir in Direction::iter_4()
         {
             let neighbour = dir.add_to(search_point);
 
@@ -214,9 +200,9 @@ fn get_number_of_sides(perimeter_points: &mut HashSet<(Point, Direction)>) -> us
 
     while let Some(side_seed) = perimeter_points.iter().next()
     {
-        let side_seed = side_seed.clone();
+        let side_seed = *side_seed;
         let side_point = side_seed.0;
-        let side_normal = side_seed.1.clone();
+        let side_normal = side_seed.1;
         num_sides += 1;
 
         perimeter_points.remove(&side_seed);
@@ -228,7 +214,7 @@ fn get_number_of_sides(perimeter_points: &mut HashSet<(Point, Direction)>) -> us
         {
             let mut remove_point = dir.add_to(side_point);
 
-            while perimeter_points.remove(&(remove_point, side_normal.clone()))
+            while perimeter_points.remove(&(remove_point, side_normal))
             {
                 remove_point = dir.add_to(remove_point);
             }
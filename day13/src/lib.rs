@@ -0,0 +1,7 @@
+#![allow(dead_code)]
+
+mod tests;
+pub mod part1;
+pub mod part2;
+pub mod solution;
+pub mod prize_machine;
\ No newline at end of file
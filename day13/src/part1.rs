@@ -1,9 +1,15 @@
 use crate::prize_machine::PrizeMachine;
+use aoc_core::parse_error::ParseError;
 
-pub fn get_min_token_cost(input: &String) -> i64
+pub fn get_min_token_cost(input: &String) -> Result<i64, ParseError>
 {
-    let machines = PrizeMachine::parse_list(input);
+    let machines = PrizeMachine::parse_list(input)?;
 
+    return Ok(total_min_token_cost(&machines));
+}
+
+pub fn total_min_token_cost(machines: &Vec<PrizeMachine>) -> i64
+{
     let mut total_cost = 0;
     for machine in machines.iter()
     {
@@ -1,11 +1,20 @@
 use crate::prize_machine::PrizeMachine;
+use aoc_core::parse_error::ParseError;
 use glam::I64Vec2;
 
-pub fn get_min_token_cost(input: &String) -> i64
+pub fn get_min_token_cost(input: &String) -> Result<i64, ParseError>
+{
+    let machines = PrizeMachine::parse_list(input)?;
+
+    return Ok(total_min_token_cost(&machines));
+}
+
+// Same machines, with the prizes moved much further away.
+pub fn total_min_token_cost(machines: &Vec<PrizeMachine>) -> i64
 {
     const PRIZE_ADD : i64 = 10000000000000;
 
-    let mut machines = PrizeMachine::parse_list(input);
+    let mut machines = machines.clone();
     for machine in machines.iter_mut()
     {
         machine.add_to_prize(I64Vec2::new(PRIZE_ADD, PRIZE_ADD));
@@ -1,11 +1,13 @@
 use glam::{I64Vec2, DVec2};
-use regex::Regex;
 
+use aoc_core::parse_error::*;
+
+#[derive(Clone)]
 pub struct PrizeMachine
 {
-    m_a: I64Vec2,
-    m_b: I64Vec2,
-    m_prize: I64Vec2
+    pub m_a: I64Vec2,
+    pub m_b: I64Vec2,
+    pub m_prize: I64Vec2
 }
 
 impl PrizeMachine
@@ -13,34 +15,53 @@ impl PrizeMachine
     const A_BUTTON_COST: i64 = 3;
     const B_BUTTON_COST: i64 = 1;
 
-    pub fn from(input: &str) -> Self
+    // Takes the three numbered lines describing one machine.
+    pub fn from(lines: &[(usize, &str)]) -> Result<Self, ParseError>
     {
-        let button_a_parse = Regex::new(r"Button A: X\+(\d+).*Y\+(\d+)").expect("Invalid input str.");
-        let captures = button_a_parse.captures(input).unwrap();
-        let button_a_vec: I64Vec2 = I64Vec2::new(captures[1].parse().unwrap(), captures[2].parse().unwrap());
-
-        let button_b_parse = Regex::new(r"Button B: X\+(\d+).*Y\+(\d+)").expect("Invalid input str.");
-        let captures = button_b_parse.captures(input).unwrap();
-        let button_b_vec: I64Vec2 = I64Vec2::new(captures[1].parse().unwrap(), captures[2].parse().unwrap());
+        if lines.len() != 3
+        {
+            return Err(ParseError::new(lines[0].0, 1, format!("expected 3 lines per machine, found {}", lines.len())));
+        }
 
-        let prize_parse = Regex::new(r"Prize: X=(\d+).*Y=(\d+)").expect("Invalid input str.");
-        let captures = prize_parse.captures(input).unwrap();
-        let prize_vec: I64Vec2 = I64Vec2::new(captures[1].parse().unwrap(), captures[2].parse().unwrap());
+        let button_a_vec = parse_vec_line(lines[0], "Button A:")?;
+        let button_b_vec = parse_vec_line(lines[1], "Button B:")?;
+        let prize_vec = parse_vec_line(lines[2], "Prize:")?;
 
-        Self
+        return Ok(Self
         {
             m_a: button_a_vec,
             m_b: button_b_vec,
             m_prize: prize_vec
-        }
+        });
     }
 
-    pub fn parse_list(input: &String) -> Vec<PrizeMachine>
+    pub fn parse_list(input: &String) -> Result<Vec<PrizeMachine>, ParseError>
     {
-        return input.split("\n\n").
-                        into_iter().
-                        map(|str| Self::from(str)).
-                        collect();
+        let mut result = Vec::new();
+        let mut machine_lines: Vec<(usize, &str)> = Vec::new();
+
+        // Machines are separated by blank lines.
+        for (line_num, line) in numbered_lines(input)
+        {
+            if !line.trim().is_empty()
+            {
+                machine_lines.push((line_num, line));
+                continue;
+            }
+
+            if !machine_lines.is_empty()
+            {
+                result.push(Self::from(&machine_lines)?);
+                machine_lines.clear();
+            }
+        }
+
+        if !machine_lines.is_empty()
+        {
+            result.push(Self::from(&machine_lines)?);
+        }
+
+        return Ok(result);
     }
 
     pub fn get_minimum_cost(&self) -> Option<i64>
@@ -79,9 +100,9 @@ impl PrizeMachine
         let a_steps = try_round(a_steps);
         let b_steps = try_round(b_steps);
 
-        if a_steps.is_some() && b_steps.is_some()
+        if let (Some(a_steps), Some(b_steps)) = (a_steps, b_steps)
         {
-            return Some(I64Vec2::new(a_steps.unwrap(), b_steps.unwrap()));
+            return Some(I64Vec2::new(a_steps, b_steps));
         }
 
         return None;
@@ -146,4 +167,15 @@ fn try_round(num: f64) -> Option<i64>
     }
 
     return Some(rounded as i64);
+}
+
+fn parse_vec_line((line_num, line): (usize, &str), prefix: &str) -> Result<I64Vec2, ParseError>
+{
+    if !line.starts_with(prefix)
+    {
+        return Err(ParseError::new(line_num, 1, format!("expected a line starting with '{prefix}'")));
+    }
+
+    let nums: Vec<i64> = expect_line_nums(line_num, line, 2)?;
+    return Ok(I64Vec2::new(nums[0], nums[1]));
 }
\ No newline at end of file
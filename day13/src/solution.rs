@@ -0,0 +1,37 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+use crate::prize_machine::PrizeMachine;
+
+pub struct Day13;
+
+impl Solution for Day13
+{
+    type Params = NoParams;
+    type Parsed1 = Vec<PrizeMachine>;
+    type Parsed2 = Vec<PrizeMachine>;
+
+    const DAY: u32 = 13;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return PrizeMachine::parse_list(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part1::total_min_token_cost(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return PrizeMachine::parse_list(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part2::total_min_token_cost(parsed));
+    }
+}
\ No newline at end of file
@@ -2,6 +2,7 @@
 mod tests 
 {
     use crate::part1;
+    use aoc_core::parse_error::ParseError;
 
     const TEST_STR: &str = r"Button A: X+94, Y+34
 Button B: X+22, Y+67
@@ -23,6 +24,13 @@ Prize: X=18641, Y=10279";
     fn part_1_test() 
     {
         let result = part1::get_min_token_cost(&String::from(TEST_STR));
-        assert_eq!(result, 480);
+        assert_eq!(result, Ok(480));
+    }
+
+    #[test]
+    fn missing_prize_test() 
+    {
+        let result = part1::get_min_token_cost(&String::from("Button A: X+94, Y+34\nButton B: X+22, Y+67\nX=8400, Y=5400"));
+        assert_eq!(result, Err(ParseError::new(3, 1, "expected a line starting with 'Prize:'")));
     }
 }
\ No newline at end of file
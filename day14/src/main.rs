@@ -1,27 +1,8 @@
-#![allow(dead_code)]
+use aoc_core::solution::run_main;
 
-mod tests;
-mod part1;
-mod part2;
-mod aoc_utils;
-mod robot_room;
-
-use std::fs;
-
-fn read_all_lines(path : String) -> String
-{
-    let contents : String = fs::read_to_string(path).expect("Should have been able to read the file");
-    return contents;
-}
+use day14::solution::Day14;
 
 fn main()
 {
-    let file_path : String = String::from("./input.txt");
-    let file_contents = read_all_lines(file_path);
-
-    let part1 = part1::get_robot_safety_factor(&file_contents, 101, 103);
-    let part2 = part2::step_until_tree(&file_contents, 101, 103);
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
+    run_main::<Day14>();
 }
\ No newline at end of file
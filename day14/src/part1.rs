@@ -1,10 +1,18 @@
 use crate::robot_room::RobotRoom;
+use aoc_core::parse_error::ParseError;
 
 const SIM_SECONDS: i32 = 100;
 
-pub fn get_robot_safety_factor(input: &String, width: i32, height: i32) -> i32
+pub fn get_robot_safety_factor(input: &String, width: i32, height: i32) -> Result<i32, ParseError>
 {
-    let mut robot_room = RobotRoom::from(input.as_str(), width, height);
+    let robot_room = RobotRoom::from(input.as_str(), width, height)?;
+
+    return Ok(safety_factor_after_sim(&robot_room));
+}
+
+pub fn safety_factor_after_sim(robot_room: &RobotRoom) -> i32
+{
+    let mut robot_room = robot_room.clone();
     robot_room.simulate_seconds(SIM_SECONDS);
 
     return robot_room.get_safety_factor();
@@ -1,19 +1,25 @@
 use std::io::{self, Write};
 use crate::robot_room::RobotRoom;
+use aoc_core::parse_error::ParseError;
 
-pub fn step_until_tree(input: &String, width: i32, height: i32) -> i32
+pub fn step_until_tree(input: &String, width: i32, height: i32) -> Result<i32, ParseError>
 {
-    let mut robot_room = RobotRoom::from(input.as_str(), width, height);
+    let robot_room = RobotRoom::from(input.as_str(), width, height)?;
+
+    return Ok(step_room_until_tree(&robot_room));
+}
+
+pub fn step_room_until_tree(robot_room: &RobotRoom) -> i32
+{
+    let mut robot_room = robot_room.clone();
 
     let mut step_num = 0;
     loop
     {
-        let room_str = robot_room.to_string();
-
         if robot_room.get_likely_points().len() > 2 // Filter out cases that aren't likely to contain a tree
         {
             println!("Step {step_num}:");
-            println!("{}", room_str);
+            println!("{}", robot_room.render());
     
             // Ask human for approval.
             let is_tree = get_input();
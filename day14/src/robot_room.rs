@@ -1,13 +1,18 @@
 use std::collections::HashSet;
+use std::fmt;
 
 use glam::IVec2;
-use crate::aoc_utils::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::char_grid::CharGrid;
+use aoc_core::grid_image::FrameWriter;
+use aoc_core::grid_render::{Colour, GridRenderer};
+use aoc_core::parse_error::*;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Robot
 {
-    m_pos: IVec2,
-    m_speed: IVec2
+    pub m_pos: IVec2,
+    pub m_speed: IVec2
 }
 
 impl Robot 
@@ -22,43 +27,59 @@ impl Robot
     }
 }
 
+#[derive(Clone)]
 pub struct RobotRoom
 {
-    m_robots : Vec<Robot>,
-    m_height: i32,
-    m_width: i32
+    pub m_robots : Vec<Robot>,
+    pub m_height: i32,
+    pub m_width: i32
 }
 
 impl RobotRoom
 {
-    pub fn from(str: &str, width: i32, height: i32) -> Self
+    pub fn from(str: &str, width: i32, height: i32) -> Result<Self, ParseError>
     {
         let mut robots = Vec::new();
 
-        let lines_numbers = get_str_nums(str);
-
-        for line_numbers in lines_numbers.iter()
+        for (line_num, line) in numbered_lines(str)
         {
+            if line.trim().is_empty()
+            {
+                continue;
+            }
+
+            // p=x,y v=dx,dy
+            let line_numbers = expect_line_nums(line_num, line, 4)?;
             robots.push(Robot::from(&line_numbers));
         }
 
-        Self
+        return Ok(Self
         {
             m_robots: robots,
             m_height: height,
             m_width: width
-        }
+        });
     }
 
     pub fn simulate_seconds(&mut self, seconds: i32)
     {
         for r in self.m_robots.iter_mut()
         {
-            r.m_pos = r.m_pos + r.m_speed * seconds;
+            r.m_pos = wrap_vec(r.m_pos + r.m_speed * seconds, IVec2::new(self.m_width, self.m_height));
+        }
+    }
 
-            r.m_pos.x = positive_mod(r.m_pos.x, self.m_width);
-            r.m_pos.y = positive_mod(r.m_pos.y, self.m_height);
+    // Writes a frame for the starting positions and one after every second.
+    pub fn simulate_with_frames(&mut self, seconds: i32, frames: &mut FrameWriter) -> Result<(), String>
+    {
+        frames.write_frame(&self.to_grid())?;
+        for _ in 0..seconds
+        {
+            self.simulate_seconds(1);
+            frames.write_frame(&self.to_grid())?;
         }
+
+        return Ok(());
     }
 
     pub fn get_safety_factor(&self) -> i32
@@ -113,14 +134,40 @@ impl RobotRoom
 
             if all_below
             {
-                result.insert(pos.clone());
+                result.insert(pos);
             }
         }
 
         return result;
     }
 
-    pub fn to_string(&self) -> String
+    // Robots are '#'.
+    pub fn to_grid(&self) -> CharGrid
+    {
+        let mut grid = CharGrid::from_char('.', self.m_width, self.m_height);
+        for r in self.m_robots.iter()
+        {
+            grid[r.m_pos] = '#';
+        }
+
+        return grid;
+    }
+
+    // Coloured for the terminal, with the likely tree points highlighted.
+    pub fn render(&self) -> String
+    {
+        let grid = self.to_grid();
+        let mut renderer = GridRenderer::from(&grid);
+        renderer.set_colour('#', Some(Colour::Green));
+        renderer.add_layer(self.get_likely_points(), Some('@'), Colour::Red);
+
+        return renderer.render();
+    }
+}
+
+impl fmt::Display for RobotRoom
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
         let mut pos_set = HashSet::new();
 
@@ -155,6 +202,6 @@ impl RobotRoom
             chars.push('\n');
         }
 
-        return chars.into_iter().collect();
+        return write!(f, "{}", chars.into_iter().collect::<String>());
     }
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+use crate::robot_room::RobotRoom;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day14Params
+{
+    // Size of the room the robots walk around.
+    #[serde(rename = "width")]
+    pub m_width: i32,
+    #[serde(rename = "height")]
+    pub m_height: i32,
+}
+
+impl Default for Day14Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_width: 101,
+            m_height: 103,
+        };
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14
+{
+    type Params = Day14Params;
+    type Parsed1 = RobotRoom;
+    type Parsed2 = RobotRoom;
+
+    const DAY: u32 = 14;
+    const PART_KINDS: [PartKind; 2] = [PartKind::Solver, PartKind::Interactive];
+
+    fn parse_part1(input: &String, params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return RobotRoom::from(input.as_str(), params.m_width, params.m_height);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &Self::Params) -> Answer
+    {
+        return Answer::from(part1::safety_factor_after_sim(parsed));
+    }
+
+    fn parse_part2(input: &String, params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return RobotRoom::from(input.as_str(), params.m_width, params.m_height);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &Self::Params) -> Answer
+    {
+        return Answer::from(part2::step_room_until_tree(parsed));
+    }
+}
\ No newline at end of file
@@ -2,6 +2,10 @@
 mod tests 
 {
     use crate::part1;
+    use crate::solution::{Day14, Day14Params};
+    use aoc_core::config::params_from_toml;
+    use aoc_core::parse_error::ParseError;
+    use aoc_core::solution::{Answer, Solution};
 
     const TEST_STR: &str = r"p=0,4 v=3,-3
 p=6,3 v=-1,-3
@@ -20,6 +24,21 @@ p=9,5 v=-3,-3";
     fn part_1_test() 
     {
         let result = part1::get_robot_safety_factor(&String::from(TEST_STR), 11, 7);
-        assert_eq!(result, 12);
+        assert_eq!(result, Ok(12));
+    }
+
+    #[test]
+    fn config_test() 
+    {
+        let params : Day14Params = params_from_toml("width = 11\nheight = 7").unwrap();
+        let result = Day14::part1(&String::from(TEST_STR), &params);
+        assert_eq!(result, Ok(Answer::Signed(12)));
+
+        // Keys that are left out keep the real puzzle's value.
+        let params : Day14Params = params_from_toml("height = 7").unwrap();
+        assert_eq!(params, Day14Params { m_width: 101, m_height: 7 });
+
+        let params : Result<Day14Params, ParseError> = params_from_toml("width = 11\nheigth = 7");
+        assert_eq!(params.map_err(|err| err.m_line), Err(2));
     }
 }
\ No newline at end of file
@@ -1,40 +1,36 @@
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
 
 use glam::IVec2;
 
-use crate::{char_grid::CharGrid, direction::Direction};
+use aoc_core::{char_grid::CharGrid, direction::Direction};
+use aoc_core::grid_image::FrameWriter;
+use aoc_core::parse_error::ParseError;
 
-struct RobotRoom
+use crate::warehouse::{parse_warehouse, Warehouse, WarehouseTile};
+
+#[derive(Clone)]
+pub struct RobotRoom
 {
-    pub m_tiles: CharGrid,
+    pub m_tiles: Warehouse,
     pub m_robot: IVec2,
     pub m_instructions: Vec<Direction>
 }
 
 impl RobotRoom
 {
-    pub fn from(str: &str) -> Self
+    pub fn from(str: &str) -> Result<Self, ParseError>
     {
-        let (grid_str, instructions_str) = str.split_once("\n\n").unwrap();
-
-        let mut grid = CharGrid::from(grid_str);
-        let instructions : Vec<Direction> = instructions_str.
-                                    chars().
-                                    filter_map(|c| Direction::from(c)).collect();
-        
-        let robot_pos = grid.find_first('@').unwrap();
+        let (grid, robot_pos, instructions) = parse_warehouse(str)?;
 
-        let _ = grid.set(robot_pos.x, robot_pos.y, '.');
-
-        Self
+        return Ok(Self
         {
             m_tiles: grid,
             m_robot: robot_pos,
             m_instructions: instructions
-        }
+        });
     }
 
     pub fn process_all_instructions(&mut self)
@@ -44,39 +40,50 @@ impl RobotRoom
 
         for dir in instructions_copy.iter()
         {
-            self.move_robot(dir.clone());
+            self.move_robot(*dir);
+        }
+    }
+
+    // Writes a frame before the first move and after every move.
+    pub fn process_all_instructions_with_frames(&mut self, frames: &mut FrameWriter) -> Result<(), String>
+    {
+        frames.write_frame(&self.to_grid())?;
+        for dir in self.m_instructions.clone()
+        {
+            self.move_robot(dir);
+            frames.write_frame(&self.to_grid())?;
         }
+
+        return Ok(());
+    }
+
+    // The tiles with the robot drawn back in.
+    pub fn to_grid(&self) -> CharGrid
+    {
+        let mut grid = self.m_tiles.to_char_grid();
+        grid[self.m_robot] = '@';
+
+        return grid;
     }
 
-    fn move_robot(&mut self, dir: Direction)
+    pub fn move_robot(&mut self, dir: Direction)
     {
         let mut scan_pos = dir.add_to(self.m_robot);
 
         // Keep scanning for free space
         loop
         {
-            let char_at = self.m_tiles.at_vec(scan_pos);
-            if char_at.is_none()
-            {
-                // Can't move outside of map
-                panic!("How did we get outside of the map?");
-            }
-            let char_at = char_at.unwrap();
+            // Can't move outside of map
+            let tile = self.m_tiles.at_vec(scan_pos).expect("How did we get outside of the map?");
 
-            if char_at == '#'
+            match tile
             {
                 // Hit wall, can't move.
-                return;
-            }
-
-            if char_at == '.'
-            {
+                WarehouseTile::Wall => { return; }
                 // Empty space, can move here.
-                break;
+                WarehouseTile::Floor => { break; }
+                WarehouseTile::Box => { scan_pos = dir.add_to(scan_pos); }
             }
-
-            assert!(char_at == 'O', "Unexpected char found:|{char_at}|");
-            scan_pos = dir.add_to(scan_pos);
         }
 
         // Move robot in dir.
@@ -84,20 +91,13 @@ impl RobotRoom
 
 
         // Push any boxes.
-        let char_at_robot = self.m_tiles.at_vec(self.m_robot).expect("Robot on invalid tile.");
-
-        // Move robot
-        if char_at_robot == 'O'
+        if self.m_tiles[self.m_robot] == WarehouseTile::Box
         {
             // Put box at end.
-            let _ = self.m_tiles.set_v(scan_pos, 'O');
+            self.m_tiles[scan_pos] = WarehouseTile::Box;
 
             // Remove box at start.
-            let _ = self.m_tiles.set_v(self.m_robot, '.');
-        }
-        else
-        {
-            assert!(char_at_robot == '.', "Robot not on empty space.");    
+            self.m_tiles[self.m_robot] = WarehouseTile::Floor;
         }
     }
 
@@ -108,8 +108,7 @@ impl RobotRoom
         {
             for y in 0..self.m_tiles.m_height
             {
-                let char_at = self.m_tiles.at(x, y).unwrap();
-                if char_at == 'O'
+                if self.m_tiles.at(x, y) == Some(WarehouseTile::Box)
                 {
                     result.push(x + y * 100);
                 }
@@ -120,9 +119,16 @@ impl RobotRoom
     }
 }
 
-pub fn compute_answer(input: &String) -> i32
+pub fn compute_answer(input: &String) -> Result<i32, ParseError>
+{
+    let robot_room = RobotRoom::from(input.as_str())?;
+
+    return Ok(sum_gps_coords_after_moves(&robot_room));
+}
+
+pub fn sum_gps_coords_after_moves(robot_room: &RobotRoom) -> i32
 {
-    let mut robot_room = RobotRoom::from(&input.as_str());
+    let mut robot_room = robot_room.clone();
     robot_room.process_all_instructions();
 
     return robot_room.get_all_gps_coords().iter().sum();
@@ -1,84 +1,100 @@
-use std::collections::HashSet;
+use std::fmt;
 use std::thread::panicking;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
 
 use glam::{Vec2, IVec2};
 
-use crate::{char_grid::CharGrid, direction::Direction};
+use aoc_core::{char_grid::CharGrid, direction::Direction};
+use aoc_core::grid_image::FrameWriter;
+use aoc_core::parse_error::ParseError;
+use aoc_core::sparse_grid::SparseGrid;
+
+use crate::warehouse::{parse_warehouse, WarehouseTile};
 
 use std::io::{self, Write};
 
-struct RobotRoom
+#[derive(Clone)]
+pub struct RobotRoom
 {
     pub m_robot: IVec2,
-    pub m_walls: HashSet<IVec2>,
-    pub m_boxes: HashSet<IVec2>,
+    // Walls as '#' and each box as '[' and ']', anything else is floor.
+    pub m_room: SparseGrid<char>,
     pub m_size: IVec2
 }
 
 impl RobotRoom
 {
-    pub fn parse_room_and_instructions(str: &str) -> (RobotRoom, Vec<Direction>)
+    pub fn parse_room_and_instructions(str: &str) -> Result<(RobotRoom, Vec<Direction>), ParseError>
     {
-        let (grid_str, instructions_str) = str.split_once("\n\n").unwrap();
+        let (grid, robot_pos, instructions) = parse_warehouse(str)?;
 
-        let grid = CharGrid::from(grid_str);
-        let instructions : Vec<Direction> = instructions_str.
-                                    chars().
-                                    filter_map(|c| Direction::from(c)).collect();
-        
-        let mut robot_pos: IVec2 = IVec2::ZERO;
-        let mut walls = HashSet::new();
-        let mut boxes = HashSet::new();
+        let mut room = SparseGrid::from('.');
 
         for x in 0..grid.m_width
         {
             for y in 0..grid.m_height
             {
-                let char_at = grid.at(x, y).unwrap();
                 let virt_coord1 = IVec2::new(2*x, y);
                 let virt_coord2 = IVec2::new(2*x + 1, y);
 
-                match char_at
+                match grid.at(x, y).unwrap()
                 {
-                    '@' => { robot_pos = virt_coord1; }
-                    '#' => { walls.insert(virt_coord1); walls.insert(virt_coord2); }
-                    'O' => { boxes.insert(virt_coord1); }
-                    '.' => {}
-                    _ => { panic!("Unexpected char found."); }
+                    WarehouseTile::Wall => { let _ = room.set(virt_coord1, '#'); let _ = room.set(virt_coord2, '#'); }
+                    WarehouseTile::Box => { let _ = room.set(virt_coord1, '['); let _ = room.set(virt_coord2, ']'); }
+                    WarehouseTile::Floor => {}
                 }
             }
         }
 
         let robot_room = RobotRoom {
-            m_robot: robot_pos,
-            m_walls: walls,
-            m_boxes: boxes,
+            m_robot: IVec2::new(2 * robot_pos.x, robot_pos.y),
+            m_room: room,
             m_size: IVec2::new(grid.m_width, grid.m_height)
         };
 
-        return (robot_room, instructions);
+        return Ok((robot_room, instructions));
     }
 
     pub fn process_all_instructions(&mut self, instructions: &Vec<Direction>)
     {
         for dir in instructions.iter()
         {
-            self.move_robot(dir.clone());
+            self.move_robot(*dir);
         }
     }
 
-    fn move_robot(&mut self, dir: Direction)
+    // Writes a frame before the first move and after every move.
+    pub fn process_all_instructions_with_frames(&mut self, instructions: &Vec<Direction>, frames: &mut FrameWriter) -> Result<(), String>
+    {
+        frames.write_frame(&self.to_grid())?;
+        for dir in instructions.iter()
+        {
+            self.move_robot(*dir);
+            frames.write_frame(&self.to_grid())?;
+        }
+
+        return Ok(());
+    }
+
+    pub fn to_grid(&self) -> CharGrid
+    {
+        let mut grid = self.m_room.to_grid(IVec2::ZERO, IVec2::new(self.m_size.x * 2, self.m_size.y));
+        let _ = grid.set_v(self.m_robot, '@');
+
+        return grid;
+    }
+
+    pub fn move_robot(&mut self, dir: Direction)
     {
         let next_pos = dir.add_to(self.m_robot);
         let seed_box = self.check_for_box(next_pos);
 
         // Simple case: wall
-        if self.m_walls.contains(&next_pos)
+        if *self.m_room.get(next_pos) == '#'
         {
             // Can't walk here.
             return;
@@ -91,7 +107,7 @@ impl RobotRoom
             return;
         }
 
-        let prev_boxes = self.m_boxes.clone();
+        let prev_room = self.m_room.clone();
         let can_push = self.try_move_box_recurse(seed_box.unwrap(), dir);
         if can_push
         {
@@ -100,7 +116,7 @@ impl RobotRoom
         else
         {
             // Revert all boxes.
-            self.m_boxes = prev_boxes;
+            self.m_room = prev_room;
         }
     }
 
@@ -120,22 +136,19 @@ impl RobotRoom
         let boxes_to_check = if left_box==right_box { [left_box, None] } else { [left_box, right_box] };
         let mut all_boxes_can_move = true;
 
-        for pushed_box in boxes_to_check.iter()
+        for pushed_box in boxes_to_check.iter().flatten()
         {
-            if let Some(pushed_box) = pushed_box
+            if *pushed_box == box_pos
             {
-                if *pushed_box == box_pos
-                {
-                    // Found ourselves.
-                    continue;
-                }
+                // Found ourselves.
+                continue;
+            }
 
-                // Try pushing this box.
-                if !self.try_move_box_recurse(*pushed_box, dir.clone())
-                {
-                    all_boxes_can_move = false;
-                    break;
-                }
+            // Try pushing this box.
+            if !self.try_move_box_recurse(*pushed_box, dir)
+            {
+                all_boxes_can_move = false;
+                break;
             }
         }
 
@@ -145,9 +158,9 @@ impl RobotRoom
         }
 
         // move us.
-        let removed = self.m_boxes.remove(&box_pos);
+        let removed = self.m_room.remove(box_pos) == Some('[') && self.m_room.remove(box_pos + IVec2::X) == Some(']');
         assert!(removed);
-        let added = self.m_boxes.insert(next_pos);
+        let added = self.m_room.set(next_pos, '[').is_none() && self.m_room.set(next_pos + IVec2::X, ']').is_none();
         assert!(added);
 
         return true;
@@ -155,79 +168,50 @@ impl RobotRoom
 
     fn box_vs_wall(&self, pos: IVec2) -> bool
     {
-        return self.m_walls.contains(&pos) || self.m_walls.contains(&(pos + IVec2::new(1, 0)));
+        return *self.m_room.get(pos) == '#' || *self.m_room.get(pos + IVec2::new(1, 0)) == '#';
     }
 
     fn check_for_box(&self, pos: IVec2) -> Option<IVec2>
     {
-        let left_pos = pos - IVec2::X;
-
         // Check point or one to the left of point.
-        if self.m_boxes.contains(&pos)
+        match self.m_room.get(pos)
         {
-            return Some(pos);
+            '[' => { return Some(pos); }
+            ']' => { return Some(pos - IVec2::X); }
+            _ => { return None; }
         }
-        else if self.m_boxes.contains(&left_pos)
-        {
-            return Some(left_pos);    
-        }
-
-        return None;
     }
 
     fn point_free(&self, pos: IVec2) -> bool
     {
-        return !self.m_walls.contains(&pos) && self.check_for_box(pos).is_none();
+        return *self.m_room.get(pos) == '.';
     }
 
     pub fn sum_all_gps_coords(&self) -> i32
     {
-        return self.m_boxes.iter().map(|b| b.x + b.y * 100).sum();
+        return self.m_room.cells().filter(|(_, c)| **c == '[').map(|(b, _)| b.x + b.y * 100).sum();
     }
+}
 
-    pub fn to_string(&self) -> String
+impl fmt::Display for RobotRoom
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let mut chars: Vec<char> = Vec::new();
-
-        for y in 0..self.m_size.y
-        {
-            for x in 0..self.m_size.x * 2
-            {
-                let pos = IVec2::new(x, y);
-                let prev_pos = IVec2::new(x-1, y);
-                if self.m_robot == pos
-                {
-                    chars.push('@');
-                }
-                else if self.m_walls.contains(&pos)
-                {
-                    chars.push('#');
-                }
-                else if self.m_boxes.contains(&pos)
-                {
-                    chars.push('[');
-                }
-                else if self.m_boxes.contains(&prev_pos)
-                {
-                    chars.push(']');
-                }
-                else
-                {
-                    chars.push('.');
-                }
-            }
+        return write!(f, "{}", self.to_grid());
+    }
+}
 
-            chars.push('\n');
-        }
+pub fn compute_answer(input: &String) -> Result<i32, ParseError>
+{
+    let (robot_room, instructions) = RobotRoom::parse_room_and_instructions(input.as_str())?;
 
-        return chars.into_iter().collect();
-    }
+    return Ok(sum_gps_coords_after_moves(&robot_room, &instructions));
 }
 
-pub fn compute_answer(input: &String) -> i32
+pub fn sum_gps_coords_after_moves(robot_room: &RobotRoom, instructions: &Vec<Direction>) -> i32
 {
-    let (mut robot_room, instructions) = RobotRoom::parse_room_and_instructions(&input.as_str());
-    robot_room.process_all_instructions(&instructions);
+    let mut robot_room = robot_room.clone();
+    robot_room.process_all_instructions(instructions);
 
     return robot_room.sum_all_gps_coords();
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+use aoc_core::direction::Direction;
+
+use crate::{part1, part2};
+
+pub struct Day15;
+
+impl Solution for Day15
+{
+    type Params = NoParams;
+    type Parsed1 = part1::RobotRoom;
+    type Parsed2 = (part2::RobotRoom, Vec<Direction>);
+
+    const DAY: u32 = 15;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return part1::RobotRoom::from(input.as_str());
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part1::sum_gps_coords_after_moves(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return part2::RobotRoom::parse_room_and_instructions(input.as_str());
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        let (robot_room, instructions) = parsed;
+        return Answer::from(part2::sum_gps_coords_after_moves(robot_room, instructions));
+    }
+}
\ No newline at end of file
@@ -1,8 +1,12 @@
 #[cfg(test)]
 mod tests 
 {
+    use aoc_core::direction::Direction;
+    use aoc_core::parse_error::ParseError;
+
     use crate::part1;
     use crate::part2;
+    use crate::warehouse::parse_warehouse;
 
     const TEST_STR: &str = r"##########
 #..O..O.O#
@@ -30,13 +34,46 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
     fn part_1_test() 
     {
         let result = part1::compute_answer(&String::from(TEST_STR));
-        assert_eq!(result, 10092);
+        assert_eq!(result, Ok(10092));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = part2::compute_answer(&String::from(TEST_STR));
-        assert_eq!(result, 9021);
+        assert_eq!(result, Ok(9021));
+    }
+
+    #[test]
+    fn moves_trailing_whitespace_test() 
+    {
+        let (_, robot_pos, instructions) = parse_warehouse("#####\n#@O.#\n#####\n\n<> \r\n^\t\r\nv\r").unwrap();
+        assert_eq!((robot_pos.x, robot_pos.y), (1, 1));
+        assert_eq!(instructions, vec![Direction::West, Direction::East, Direction::North, Direction::South]);
+
+        let result = parse_warehouse("#####\n#@O.#\n#####\n\n< >");
+        assert_eq!(result.map(|_| ()), Err(ParseError::new(5, 2, "unexpected move ' '")));
+    }
+
+    #[test]
+    fn part_2_to_grid_test() 
+    {
+        let (mut room, instructions) = part2::RobotRoom::parse_room_and_instructions(TEST_STR).unwrap();
+        assert!(room.to_string().starts_with("####################\n##....[]....[]..[]##\n"));
+
+        room.process_all_instructions(&instructions);
+        let grid = room.to_grid();
+        assert_eq!((grid.m_width, grid.m_height), (20, 10));
+        assert_eq!(grid.to_string(), r"####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################
+");
     }
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use glam::IVec2;
+
+use aoc_core::direction::Direction;
+use aoc_core::grid::Grid;
+use aoc_core::parse_error::*;
+use aoc_core::tile_grid::Tile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarehouseTile
+{
+    Floor,
+    Wall,
+    Box,
+}
+
+impl Tile for WarehouseTile
+{
+    fn from_char(c: char) -> Option<Self>
+    {
+        match c
+        {
+            '.' => { return Some(WarehouseTile::Floor); }
+            '#' => { return Some(WarehouseTile::Wall); }
+            'O' => { return Some(WarehouseTile::Box); }
+            _ => { return None; }
+        }
+    }
+
+    fn to_char(&self) -> char
+    {
+        match self
+        {
+            WarehouseTile::Floor => { return '.'; }
+            WarehouseTile::Wall => { return '#'; }
+            WarehouseTile::Box => { return 'O'; }
+        }
+    }
+}
+
+pub type Warehouse = Grid<WarehouseTile>;
+
+// The map (with floor where the robot is), where the robot starts, and the list of moves.
+pub fn parse_warehouse(input: &str) -> Result<(Warehouse, IVec2, Vec<Direction>), ParseError>
+{
+    let sections = split_sections(input);
+    if sections.len() != 2
+    {
+        return Err(ParseError::new(1, 1, "expected the map and the moves separated by a blank line"));
+    }
+
+    let (grid_line, grid_str) = sections[0];
+    let (instructions_line, instructions_str) = sections[1];
+
+    let (grid, markers) = Warehouse::parse_tiles_with_markers(grid_str, &['@'], WarehouseTile::Floor).map_err(|err| err.offset_lines(grid_line))?;
+    let (robot_pos, _) = markers.expect_unique(&['@'], "robot (@)").map_err(|err| err.offset_lines(grid_line))?;
+
+    let mut instructions = Vec::new();
+    for (line_num, line) in numbered_lines(instructions_str)
+    {
+        // Trailing spaces and a stray '\r' from a CRLF file aren't moves.
+        for (idx, c) in line.trim_end().chars().enumerate()
+        {
+            let Some(dir) = Direction::from(c) else
+            {
+                return Err(ParseError::new(instructions_line + line_num - 1, idx + 1, format!("unexpected move '{c}'")));
+            };
+
+            instructions.push(dir);
+        }
+    }
+
+    return Ok((grid, robot_pos, instructions));
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+use glam::IVec2;
+
+use aoc_core::direction::Direction;
+use aoc_core::grid::Grid;
+use aoc_core::parse_error::ParseError;
+use aoc_core::search::SearchSpace;
+use aoc_core::tile_grid::Tile;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MazeTile
+{
+    Open,
+    Wall,
+}
+
+impl Tile for MazeTile
+{
+    fn from_char(c: char) -> Option<Self>
+    {
+        match c
+        {
+            '.' => { return Some(MazeTile::Open); }
+            '#' => { return Some(MazeTile::Wall); }
+            _ => { return None; }
+        }
+    }
+
+    fn to_char(&self) -> char
+    {
+        match self
+        {
+            MazeTile::Open => { return '.'; }
+            MazeTile::Wall => { return '#'; }
+        }
+    }
+}
+
+pub type Maze = Grid<MazeTile>;
+
+// The maze with its start and end tiles, both of which are open.
+pub fn parse_maze(input: &str) -> Result<(Maze, IVec2, IVec2), ParseError>
+{
+    let (maze, markers) = Maze::parse_tiles_with_markers(input, &['S', 'E'], MazeTile::Open)?;
+    let (start, _) = markers.expect_unique(&['S'], "start (S)")?;
+    let (end, _) = markers.expect_unique(&['E'], "end (E)")?;
+
+    return Ok((maze, start, end));
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Node
+{
+    pub m_pos: IVec2,
+    pub m_facing: Direction
+}
+
+// The reindeer's moves: a step forward costs 1 and turning on the spot costs 1000.
+pub struct MazeSearch<'a>
+{
+    pub m_maze: &'a Maze
+}
+
+impl SearchSpace for MazeSearch<'_>
+{
+    type State = Node;
+
+    fn successors(&self, node: Node) -> impl Iterator<Item = (Node, u64)>
+    {
+        let front = Node{m_pos: node.m_facing.add_to(node.m_pos), m_facing: node.m_facing};
+        let right = Node{m_pos: node.m_pos, m_facing: node.m_facing.rot_right()};
+        let left = Node{m_pos: node.m_pos, m_facing: node.m_facing.rot_left()};
+
+        // Turning to face a wall never helps.
+        let front = self.is_open(front.m_pos).then_some((front, 1));
+        let right = self.is_open(right.m_facing.add_to(right.m_pos)).then_some((right, 1000));
+        let left = self.is_open(left.m_facing.add_to(left.m_pos)).then_some((left, 1000));
+
+        return [front, right, left].into_iter().flatten();
+    }
+}
+
+impl MazeSearch<'_>
+{
+    fn is_open(&self, pos: IVec2) -> bool
+    {
+        return self.m_maze[pos] != MazeTile::Wall;
+    }
+}
\ No newline at end of file
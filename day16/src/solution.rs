@@ -0,0 +1,41 @@
+use glam::IVec2;
+
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::maze::{self, Maze};
+use crate::{part1, part2};
+
+pub struct Day16;
+
+impl Solution for Day16
+{
+    type Params = NoParams;
+    type Parsed1 = (Maze, IVec2, IVec2);
+    type Parsed2 = (Maze, IVec2, IVec2);
+
+    const DAY: u32 = 16;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return maze::parse_maze(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        let (grid, start, end) = parsed;
+        return Answer::from(part1::search_grid(grid, *start, *end));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return maze::parse_maze(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        let (grid, start, end) = parsed;
+        return Answer::from(part2::search_grid(grid, *start, *end));
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests 
 {
+    use crate::maze;
     use crate::part1;
     use crate::part2;
 
@@ -48,15 +49,27 @@ mod tests
     #[test]
     fn part_1_test() 
     {
-        assert_eq!(2006, part1::compute_answer(&String::from(TINY_MAZE_STR)));
-        assert_eq!(7036, part1::compute_answer(&String::from(SMALL_MAZE_STR)));
-        assert_eq!(11048, part1::compute_answer(&String::from(BIG_MAZE_STR)));
+        assert_eq!(Ok(2006), part1::compute_answer(&String::from(TINY_MAZE_STR)));
+        assert_eq!(Ok(7036), part1::compute_answer(&String::from(SMALL_MAZE_STR)));
+        assert_eq!(Ok(11048), part1::compute_answer(&String::from(BIG_MAZE_STR)));
     }
 
     #[test]
     fn part_2_test() 
     {
-        assert_eq!(45, part2::compute_answer(&String::from(SMALL_MAZE_STR)));
-        assert_eq!(64, part2::compute_answer(&String::from(BIG_MAZE_STR)));
+        assert_eq!(Ok(45), part2::compute_answer(&String::from(SMALL_MAZE_STR)));
+        assert_eq!(Ok(64), part2::compute_answer(&String::from(BIG_MAZE_STR)));
+    }
+
+    #[test]
+    fn parse_error_test() 
+    {
+        let unknown_tile = TINY_MAZE_STR.replacen("#.#..#", "#.#X.#", 1);
+        let result = maze::parse_maze(&unknown_tile).map_err(|err| (err.m_line, err.m_column, err.m_reason));
+        assert_eq!(result, Err((3, 4, String::from("unexpected character 'X'"))));
+
+        let two_starts = TINY_MAZE_STR.replacen("#...E#", "#S..E#", 1);
+        let result = maze::parse_maze(&two_starts).map_err(|err| (err.m_line, err.m_column));
+        assert_eq!(result, Err((5, 2)));
     }
 }
\ No newline at end of file
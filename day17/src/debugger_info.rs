@@ -0,0 +1,46 @@
+use aoc_core::parse_error::*;
+
+// Initial A, B and C registers and the program, as given by the debugger.
+pub fn parse_debugger_info(input: &str) -> Result<([u64; 3], Vec<u64>), ParseError>
+{
+    const PREFIXES: [&str; 3] = ["Register A:", "Register B:", "Register C:"];
+
+    let lines: Vec<(usize, &str)> = numbered_lines(input).filter(|(_, line)| !line.trim().is_empty()).collect();
+    if lines.len() != 4
+    {
+        return Err(ParseError::new(1, 1, format!("expected 3 registers and a program, found {} lines", lines.len())));
+    }
+
+    let mut registers = [0; 3];
+    for (i, prefix) in PREFIXES.iter().enumerate()
+    {
+        let (line_num, line) = lines[i];
+        if !line.starts_with(prefix)
+        {
+            return Err(ParseError::new(line_num, 1, format!("expected a line starting with '{prefix}'")));
+        }
+
+        registers[i] = expect_line_nums::<u64>(line_num, line, 1)?[0];
+    }
+
+    let (line_num, line) = lines[3];
+    let Some(program_str) = line.strip_prefix("Program:") else
+    {
+        return Err(ParseError::new(line_num, 1, "expected a line starting with 'Program:'"));
+    };
+
+    let mut program = Vec::new();
+    for op in program_str.split(',')
+    {
+        let op = op.trim();
+        let value: u64 = parse_token(line_num, line, op)?;
+        if value > 7
+        {
+            return Err(ParseError::at_token(line_num, line, op, format!("{value} isn't a 3-bit number")));
+        }
+
+        program.push(value);
+    }
+
+    return Ok((registers, program));
+}
\ No newline at end of file
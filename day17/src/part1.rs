@@ -1,32 +1,29 @@
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
 
-#[derive(Debug)]
-struct Computer
+use crate::debugger_info::parse_debugger_info;
+
+#[derive(Debug, Clone)]
+pub struct Computer
 {
-    ra: u64,
-    rb: u64,
-    rc: u64,
-    pc: usize,
-    program: Vec<u64>,
-    output_buf: Vec<u64>
+    pub ra: u64,
+    pub rb: u64,
+    pub rc: u64,
+    pub pc: usize,
+    pub program: Vec<u64>,
+    pub output_buf: Vec<u64>
 }
 
 impl Computer
 {
-    pub fn from(input: &str) -> Self
+    pub fn from(input: &str) -> Result<Self, ParseError>
     {
-        let str_nums = get_str_nums(input);
-
-        let a = str_nums[0][0] as u64;
-        let b = str_nums[1][0] as u64;
-        let c = str_nums[2][0] as u64;
+        let ([a, b, c], prog) = parse_debugger_info(input)?;
 
-        let prog = str_nums[4].iter().map(|i| *i as u64).collect();
-
-        Self
+        return Ok(Self
         {
             ra: a,
             rb: b,
@@ -34,7 +31,7 @@ impl Computer
             pc: 0,
             program: prog,
             output_buf: Vec::new()
-        }
+        });
     }
 
     // Process a step and return if halted
@@ -53,12 +50,12 @@ impl Computer
         {
             0 => // adv
             {
-                self.ra = self.ra >> self.combo_operand_value(operand);
+                self.ra >>= self.combo_operand_value(operand);
                 self.pc += 2;
             }
             1 => // bxl
             {
-                self.rb = self.rb ^ operand;
+                self.rb ^= operand;
                 self.pc += 2;
             }
             2 => // bst
@@ -79,7 +76,7 @@ impl Computer
             }
             4 => // bxc
             {
-                self.rb = self.rb ^ self.rc;
+                self.rb ^= self.rc;
                 self.pc += 2;
             }
             5 => // out
@@ -121,9 +118,16 @@ impl Computer
 
 }
 
-pub fn compute_answer(input: &str) -> String
+pub fn compute_answer(input: &str) -> Result<String, ParseError>
+{
+    let computer = Computer::from(input)?;
+
+    return Ok(run_program(&computer));
+}
+
+pub fn run_program(computer: &Computer) -> String
 {
-    let mut computer = Computer::from(input);
+    let mut computer = computer.clone();
 
     while !computer.proc_step()
     {
@@ -1,34 +1,31 @@
 use std::cmp;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
+
+use crate::debugger_info::parse_debugger_info;
 
 #[derive(Debug, Clone)]
-struct Computer
+pub struct Computer
 {
-    ra: u64,
-    rb: u64,
-    rc: u64,
-    pc: usize,
-    program: Vec<u64>,
-    output_buf: Vec<u64>
+    pub ra: u64,
+    pub rb: u64,
+    pub rc: u64,
+    pub pc: usize,
+    pub program: Vec<u64>,
+    pub output_buf: Vec<u64>
 }
 
 impl Computer
 {
-    pub fn from(input: &str) -> Self
+    pub fn from(input: &str) -> Result<Self, ParseError>
     {
-        let str_nums = get_str_nums(input);
-
-        let a = str_nums[0][0] as u64;
-        let b = str_nums[1][0] as u64;
-        let c = str_nums[2][0] as u64;
-
-        let prog = str_nums[4].iter().map(|i| *i as u64).collect();
+        let ([a, b, c], prog) = parse_debugger_info(input)?;
 
-        Self
+        return Ok(Self
         {
             ra: a,
             rb: b,
@@ -36,7 +33,7 @@ impl Computer
             pc: 0,
             program: prog,
             output_buf: Vec::new()
-        }
+        });
     }
 
     // Process a step and return if halted
@@ -55,12 +52,12 @@ impl Computer
         {
             0 => // adv
             {
-                self.ra = self.ra >> self.combo_operand_value(operand);
+                self.ra >>= self.combo_operand_value(operand);
                 self.pc += 2;
             }
             1 => // bxl
             {
-                self.rb = self.rb ^ operand;
+                self.rb ^= operand;
                 self.pc += 2;
             }
             2 => // bst
@@ -81,7 +78,7 @@ impl Computer
             }
             4 => // bxc
             {
-                self.rb = self.rb ^ self.rc;
+                self.rb ^= self.rc;
                 self.pc += 2;
             }
             5 => // out
@@ -123,12 +120,19 @@ impl Computer
 
 }
 
-pub fn compute_answer(input: &str) -> u64
+pub fn compute_answer(input: &str) -> Result<Option<u64>, ParseError>
+{
+    let computer = Computer::from(input)?;
+
+    return Ok(find_quine_input(&computer));
+}
+
+// Smallest A register that makes the program output itself, None if the search doesn't find one.
+pub fn find_quine_input(computer: &Computer) -> Option<u64>
 {
     const ANSWER_SHIFT: u64 = 3; // abuse specific knowledge of program. A register always shift by 3
 
     // New dumbass approach.
-    let computer = Computer::from(input);
 
     let expected_output = computer.program.clone();
     let num_answer_bits = (expected_output.len() as u64) * ANSWER_SHIFT;
@@ -137,8 +141,6 @@ pub fn compute_answer(input: &str) -> u64
 
     let mut answer: u64 = 0;
 
-    println!("Looking for {} bits", num_answer_bits);
-
     let mut section_search_size = ANSWER_SHIFT * 2;
 
     while bits_found < num_answer_bits - ANSWER_SHIFT
@@ -148,7 +150,7 @@ pub fn compute_answer(input: &str) -> u64
         {
             let test_input = answer | (new_section << (num_answer_bits - bits_found - section_search_size));
 
-            let output = simulate_computer(&computer, test_input);
+            let output = simulate_computer(computer, test_input);
 
             if output.len() != expected_output.len()
             {
@@ -161,7 +163,6 @@ pub fn compute_answer(input: &str) -> u64
 
             for i in 0..outputs_to_satisfy
             {
-                let i = i as usize;
                 let size = expected_output.len();
                 let idx = size - i - 1;
 
@@ -176,8 +177,6 @@ pub fn compute_answer(input: &str) -> u64
             {
                 answer = test_input;
                 bits_found += section_search_size;
-                println!("Found {}", bits_found);
-                println!("Input: {} | Out: {:?} | Want {}", test_input, &output, outputs_to_satisfy);
 
                 section_search_size = 0;
                 break;
@@ -192,11 +191,12 @@ pub fn compute_answer(input: &str) -> u64
         section_search_size = cmp::min(section_search_size, num_answer_bits - bits_found);
     }
 
-    let test_output = simulate_computer(&computer, answer);
-    println!("Ans: {} | {:?}", answer, test_output);
-    assert!(test_output == expected_output);
+    if simulate_computer(computer, answer) != expected_output
+    {
+        return None;
+    }
 
-    return answer;
+    return Some(answer);
 }
 
 fn simulate_computer(computer: &Computer, reg_a: u64) -> Vec<u64>
@@ -214,7 +214,7 @@ fn simulate_computer(computer: &Computer, reg_a: u64) -> Vec<u64>
 
 fn round_up_to_section_size(num: u64, section_size: u64) -> u64 
 {
-    if num % section_size == 0
+    if num.is_multiple_of(section_size)
     {
         return num;
     }
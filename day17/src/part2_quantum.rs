@@ -1,10 +1,10 @@
 use std::u64;
 use std::usize;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
 
 /////////////////////////////////////////////////////////////////////////////
 // 
@@ -0,0 +1,40 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+
+pub struct Day17;
+
+impl Solution for Day17
+{
+    type Params = NoParams;
+    type Parsed1 = part1::Computer;
+    type Parsed2 = part2::Computer;
+
+    const DAY: u32 = 17;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return part1::Computer::from(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part1::run_program(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return part2::Computer::from(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        match part2::find_quine_input(parsed)
+        {
+            Some(reg_a) => { return Answer::from(reg_a); }
+            None => { return Answer::from("No A register makes the program output itself"); }
+        }
+    }
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ mod tests
 {
     use crate::part1;
     use crate::part2;
+    use aoc_core::parse_error::ParseError;
 
     const TEST_STR: &str = r"Register A: 729
 Register B: 0
@@ -20,13 +21,20 @@ Program: 0,3,5,4,3,0";
     fn part_1_test() 
     {
         let result = part1::compute_answer(TEST_STR);
-        assert_eq!(result, "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(result, Ok(String::from("4,6,3,5,6,3,5,2,1,0")));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = part2::compute_answer(TEST_STR_2);
-        assert_eq!(result, 117440);
+        assert_eq!(result, Ok(Some(117440)));
+    }
+
+    #[test]
+    fn bad_opcode_test() 
+    {
+        let result = part1::compute_answer("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,9,4");
+        assert_eq!(result, Err(ParseError::new(5, 14, "9 isn't a 3-bit number")));
     }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use glam::IVec2;
+
+use aoc_core::parse_error::*;
+
+// One "x,y" per line, each of which has to land inside the memory space.
+pub fn parse_falling_bytes(input: &str, width: i32, height: i32) -> Result<Vec<IVec2>, ParseError>
+{
+    let mut result = Vec::new();
+
+    for (line_num, line) in numbered_lines(input)
+    {
+        if line.trim().is_empty()
+        {
+            continue;
+        }
+
+        let nums: Vec<i32> = expect_line_nums(line_num, line, 2)?;
+        let byte_pos = IVec2::new(nums[0], nums[1]);
+
+        if !(0..width).contains(&byte_pos.x) || !(0..height).contains(&byte_pos.y)
+        {
+            return Err(ParseError::new(line_num, 1, format!("byte at {},{} falls outside the {width}x{height} memory space", byte_pos.x, byte_pos.y)));
+        }
+
+        result.push(byte_pos);
+    }
+
+    return Ok(result);
+}
\ No newline at end of file
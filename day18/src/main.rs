@@ -1,41 +1,26 @@
-#![allow(dead_code)]
-#![allow(unused_variables)]
-#![allow(unused_imports)]
-
-mod tests;
-mod part1;
-mod part2;
-mod aoc_utils;
-mod char_grid;
-mod int_grid;
-mod direction;
-
+use std::env;
 use std::fs;
+use std::path::Path;
 
-fn read_all_lines(path : String) -> String
-{
-    let contents : String = fs::read_to_string(path).expect("Should have been able to read the file");
-    return contents;
-}
+use aoc_core::config::{load_params, CONFIG_FILE};
+use aoc_core::parse_error::unwrap_or_exit;
+use aoc_core::solution::{run_main, INPUT_FILE};
+
+use day18::falling_bytes::parse_falling_bytes;
+use day18::part1;
+use day18::solution::{Day18, Day18Params};
 
 fn main()
 {
-    let file_path : String = String::from("./input.txt");
-    let file_contents = read_all_lines(file_path);
-
-    let part1 = part1::compute_answer(&file_contents, 71, 71, 1024);
-    
-    println!("Part 1: {}", part1);
+    run_main::<Day18>();
 
-    for i in 1024..3450
+    // `--render` draws the part 1 path through the fallen bytes.
+    if env::args().any(|arg| arg == "--render")
     {
-        let part2 = part2::compute_answer(&file_contents, 71, 71, i);
+        let input = fs::read_to_string(INPUT_FILE).expect("Should have been able to read the file");
+        let params : Day18Params = unwrap_or_exit(load_params(Path::new(CONFIG_FILE)));
+        let bytes = unwrap_or_exit(parse_falling_bytes(&input, params.m_width, params.m_height));
 
-        println!("Part 2:{} {}", part2, i);
-        if !part2
-        {
-            
-            break;
-        }
+        println!("{}", part1::render_shortest_path(&bytes, params.m_width, params.m_height, params.m_num_bytes));
     }
 }
\ No newline at end of file
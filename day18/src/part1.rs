@@ -1,125 +1,61 @@
 use glam::IVec2;
 use strum::IntoEnumIterator;
-use std::collections::{BinaryHeap, HashMap};
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::grid_render::{Colour, GridRenderer};
+use aoc_core::parse_error::ParseError;
+use aoc_core::search::{astar, GridWalk};
 
-pub fn compute_answer(input: &String, width: i32, height: i32, num_bytes: i32) -> i32
-{
-    let lines_numbers = get_str_nums(&input);
-    let mut grid = CharGrid::from_char('.', width, height);
-
-    for line_numbers in lines_numbers.iter().enumerate()
-    {
-        if line_numbers.0 >= num_bytes as usize
-        {
-            break;
-        }
-        let byte_pos = IVec2::new(line_numbers.1[0], line_numbers.1[1]);
+use crate::falling_bytes::parse_falling_bytes;
 
-        let err = grid.set_v(byte_pos, '#');
-        if err.is_err()
-        {
-            panic!("Set outside bounds of grid");
-        }
-    }
-
-    let path = dijkstra(IVec2::new(0, 0), IVec2::new(width-1, height-1), &grid).expect("Couldn't find path.");
-
-    println!("{}", grid.to_string());
+pub fn compute_answer(input: &String, width: i32, height: i32, num_bytes: i32) -> Result<i32, ParseError>
+{
+    let bytes = parse_falling_bytes(input, width, height)?;
 
-    return path.len() as i32 - 1;
+    return Ok(shortest_path_len(&bytes, width, height, num_bytes));
 }
 
-/// A node in the priority queue
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct Node
+fn fallen_grid(bytes: &Vec<IVec2>, width: i32, height: i32, num_bytes: i32) -> CharGrid
 {
-    position: IVec2,
-    cost: usize,
-}
+    let mut grid = CharGrid::from_char('.', width, height);
 
-impl Ord for Node
-{
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering
+    for byte_pos in bytes.iter().take(num_bytes as usize)
     {
-        // Reverse the comparison to make BinaryHeap a min-heap
-        other.cost.cmp(&self.cost)
+        let _ = grid.set_v(*byte_pos, '#');
     }
-}
 
-impl PartialOrd for Node
-{
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
-    {
-        Some(self.cmp(other))
-    }
+    return grid;
 }
 
-/// Dijkstra's algorithm on a 2D grid
-/// - `start`: Starting point
-/// - `end`: Endpoint
-/// - `grid`: A grid to check if a tile is passable
-pub fn dijkstra(start: IVec2, end: IVec2, grid: &CharGrid) -> Option<Vec<IVec2>> {
-
-    let mut open_set = BinaryHeap::new();
-    open_set.push(Node 
-    {
-        position: start,
-        cost: 0,
-    });
-
-    // Maps positions to their cheapest cost and the preceding node
-    let mut costs = HashMap::new();
-    let mut came_from = HashMap::new();
-
-    // Initial cost for the starting position
-    costs.insert(start, 0);
-
-    while let Some(Node { position, cost }) = open_set.pop()
-    {
-        // If we've reached the end, reconstruct the path
-        if position == end
-        {
-            let mut path = vec![end];
-            while let Some(&prev) = came_from.get(path.last().unwrap())
-            {
-                path.push(prev);
-            }
-            path.reverse();
-            return Some(path);
-        }
+// Steps from the top left to the bottom right once `num_bytes` have fallen.
+pub fn shortest_path_len(bytes: &Vec<IVec2>, width: i32, height: i32, num_bytes: i32) -> i32
+{
+    let grid = fallen_grid(bytes, width, height, num_bytes);
+    let path = shortest_path(&grid, IVec2::new(0, 0), IVec2::new(width-1, height-1)).expect("Couldn't find path.");
 
-        // Explore neighbors
-        for dir in Direction::iter()
-        {
-            let neighbor_pos = dir.add_to(position);
+    return path.len() as i32 - 1;
+}
 
-            let char_at = grid.at_vec(neighbor_pos);
+// The fallen bytes coloured for the terminal, with the path from shortest_path_len drawn on.
+pub fn render_shortest_path(bytes: &Vec<IVec2>, width: i32, height: i32, num_bytes: i32) -> String
+{
+    let grid = fallen_grid(bytes, width, height, num_bytes);
+    let path = shortest_path(&grid, IVec2::new(0, 0), IVec2::new(width-1, height-1)).unwrap_or_default();
 
-            let passable = char_at == Some('.');
+    let mut renderer = GridRenderer::from(&grid);
+    renderer.add_layer(path.iter().copied(), Some('O'), Colour::Green);
 
-            if passable
-            {
-                let new_cost = cost + 1;
+    return renderer.render();
+}
 
-                if new_cost < *costs.get(&neighbor_pos).unwrap_or(&usize::MAX)
-                {
-                    costs.insert(neighbor_pos, new_cost);
-                    came_from.insert(neighbor_pos, position);
-                    open_set.push(Node
-                    {
-                        position: neighbor_pos,
-                        cost: new_cost,
-                    });
-                }
-            }
-        }
-    }
+// Every position from `start` to `end` on one of the shortest paths through the gaps.
+pub fn shortest_path(grid: &CharGrid, start: IVec2, end: IVec2) -> Option<Vec<IVec2>>
+{
+    let mut walk = GridWalk::from(grid, |c| *c == '.');
+    walk.set_goal(end);
 
-    // If we exhaust the search without finding the end
-    None
+    return astar(&walk, start, |pos| pos == end).path_to(end);
 }
\ No newline at end of file
@@ -1,123 +1,54 @@
 use glam::IVec2;
 use strum::IntoEnumIterator;
-use std::collections::{BinaryHeap, HashMap};
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
+use aoc_core::search::{bfs, GridWalk};
 
-pub fn compute_answer(input: &String, width: i32, height: i32, num_bytes: i32) -> bool
-{
-    let lines_numbers = get_str_nums(&input);
-    let mut grid = CharGrid::from_char('.', width, height);
+use crate::falling_bytes::parse_falling_bytes;
 
-    for line_numbers in lines_numbers.iter().enumerate()
-    {
-        if line_numbers.0 >= num_bytes as usize
-        {
-            break;
-        }
-        let byte_pos = IVec2::new(line_numbers.1[0], line_numbers.1[1]);
-
-        let err = grid.set_v(byte_pos, '#');
-        if err.is_err()
-        {
-            panic!("Set outside bounds of grid");
-        }
-    }
-
-    let path = dijkstra(IVec2::new(0, 0), IVec2::new(width-1, height-1), &grid);
+pub fn compute_answer(input: &String, width: i32, height: i32, num_bytes: i32) -> Result<bool, ParseError>
+{
+    let bytes = parse_falling_bytes(input, width, height)?;
 
-    return path.is_some();
+    return Ok(exit_reachable(&bytes, width, height, num_bytes));
 }
 
-/// A node in the priority queue
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct Node
+// Add bytes one at a time until the exit is cut off, returning the byte that did it.
+pub fn find_first_blocking_byte(input: &String, width: i32, height: i32, start_bytes: i32) -> Result<Option<IVec2>, ParseError>
 {
-    position: IVec2,
-    cost: usize,
-}
+    let bytes = parse_falling_bytes(input, width, height)?;
 
-impl Ord for Node
-{
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering
-    {
-        // Reverse the comparison to make BinaryHeap a min-heap
-        other.cost.cmp(&self.cost)
-    }
+    return Ok(first_blocking_byte(&bytes, width, height, start_bytes));
 }
 
-impl PartialOrd for Node
+pub fn first_blocking_byte(bytes: &Vec<IVec2>, width: i32, height: i32, start_bytes: i32) -> Option<IVec2>
 {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
-    {
-        Some(self.cmp(other))
-    }
-}
-
-/// Dijkstra's algorithm on a 2D grid
-/// - `start`: Starting point
-/// - `end`: Endpoint
-/// - `grid`: A grid to check if a tile is passable
-pub fn dijkstra(start: IVec2, end: IVec2, grid: &CharGrid) -> Option<Vec<IVec2>> {
-
-    let mut open_set = BinaryHeap::new();
-    open_set.push(Node 
+    for num_bytes in start_bytes..=(bytes.len() as i32)
     {
-        position: start,
-        cost: 0,
-    });
-
-    // Maps positions to their cheapest cost and the preceding node
-    let mut costs = HashMap::new();
-    let mut came_from = HashMap::new();
-
-    // Initial cost for the starting position
-    costs.insert(start, 0);
-
-    while let Some(Node { position, cost }) = open_set.pop()
-    {
-        // If we've reached the end, reconstruct the path
-        if position == end
+        if !exit_reachable(bytes, width, height, num_bytes)
         {
-            let mut path = vec![end];
-            while let Some(&prev) = came_from.get(path.last().unwrap())
-            {
-                path.push(prev);
-            }
-            path.reverse();
-            return Some(path);
+            return Some(bytes[num_bytes as usize - 1]);
         }
+    }
 
-        // Explore neighbors
-        for dir in Direction::iter()
-        {
-            let neighbor_pos = dir.add_to(position);
-
-            let char_at = grid.at_vec(neighbor_pos);
-
-            let passable = char_at == Some('.');
+    return None;
+}
 
-            if passable
-            {
-                let new_cost = cost + 1;
+fn exit_reachable(bytes: &Vec<IVec2>, width: i32, height: i32, num_bytes: i32) -> bool
+{
+    let mut grid = CharGrid::from_char('.', width, height);
 
-                if new_cost < *costs.get(&neighbor_pos).unwrap_or(&usize::MAX)
-                {
-                    costs.insert(neighbor_pos, new_cost);
-                    came_from.insert(neighbor_pos, position);
-                    open_set.push(Node
-                    {
-                        position: neighbor_pos,
-                        cost: new_cost,
-                    });
-                }
-            }
-        }
+    for byte_pos in bytes.iter().take(num_bytes as usize)
+    {
+        let _ = grid.set_v(*byte_pos, '#');
     }
 
-    // If we exhaust the search without finding the end
-    None
+    let end = IVec2::new(width-1, height-1);
+    let result = bfs(&GridWalk::from(&grid, |c| *c == '.'), IVec2::new(0, 0), |pos| pos == end);
+
+    return result.goal().is_some();
 }
\ No newline at end of file
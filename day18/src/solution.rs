@@ -0,0 +1,71 @@
+use glam::IVec2;
+
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+use crate::falling_bytes::parse_falling_bytes;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day18Params
+{
+    // Size of the memory space.
+    #[serde(rename = "width")]
+    pub m_width: i32,
+    #[serde(rename = "height")]
+    pub m_height: i32,
+    // Bytes that have fallen before part 1 walks through.
+    #[serde(rename = "num_bytes")]
+    pub m_num_bytes: i32,
+}
+
+impl Default for Day18Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_width: 71,
+            m_height: 71,
+            m_num_bytes: 1024,
+        };
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18
+{
+    type Params = Day18Params;
+    type Parsed1 = Vec<IVec2>;
+    type Parsed2 = Vec<IVec2>;
+
+    const DAY: u32 = 18;
+
+    fn parse_part1(input: &String, params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return parse_falling_bytes(input, params.m_width, params.m_height);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, params: &Self::Params) -> Answer
+    {
+        return Answer::from(part1::shortest_path_len(parsed, params.m_width, params.m_height, params.m_num_bytes));
+    }
+
+    fn parse_part2(input: &String, params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return parse_falling_bytes(input, params.m_width, params.m_height);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        match part2::first_blocking_byte(parsed, params.m_width, params.m_height, params.m_num_bytes)
+        {
+            Some(pos) => { return Answer::Text(format!("{},{}", pos.x, pos.y)); }
+            None => { return Answer::from("Exit never gets blocked"); }
+        }
+    }
+}
\ No newline at end of file
@@ -1,8 +1,12 @@
 #[cfg(test)]
 mod tests 
 {
+    use glam::IVec2;
+
     use crate::part1;
     use crate::part2;
+    use crate::solution::{Day18, Day18Params};
+    use aoc_core::solution::{Answer, Solution};
 
     const TEST_STR: &str = r"5,4
 4,2
@@ -34,13 +38,23 @@ mod tests
     fn part_1_test() 
     {
         let result = part1::compute_answer(&String::from(TEST_STR), 7, 7, 12);
-        assert_eq!(result, 22);
+        assert_eq!(result, Ok(22));
+    }
+
+    #[test]
+    fn part_2_test() 
+    {
+        let result = part2::find_first_blocking_byte(&String::from(TEST_STR), 7, 7, 12);
+        assert_eq!(result, Ok(Some(IVec2::new(6, 1))));
     }
 
-    // #[test]
-    // fn part_2_test() 
-    // {
-    //     let result = part2::compute_answer(&String::from(TEST_STR));
-    //     assert_eq!(result, 0);
-    // }
+    #[test]
+    fn solution_test() 
+    {
+        let params = Day18Params { m_width: 7, m_height: 7, m_num_bytes: 12 };
+        let input = String::from(TEST_STR);
+
+        assert_eq!(Day18::part1(&input, &params), Ok(Answer::Signed(22)));
+        assert_eq!(Day18::part2(&input, &params), Ok(Answer::from("6,1")));
+    }
 }
\ No newline at end of file
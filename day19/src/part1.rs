@@ -2,22 +2,27 @@ use std::str::FromStr;
 
 use fxhash::FxHashSet;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
 
-pub fn compute_answer(input: &String) -> i32
+use crate::towels::parse_towels;
+
+pub fn compute_answer(input: &String) -> Result<i32, ParseError>
 {
-    let (available_blocks, desired_patterns) = input.split_once("\n\n").unwrap();
+    let (available_blocks, desired_patterns) = parse_towels(input)?;
 
-    let available_blocks : Vec<String> = available_blocks.split(", ").map(|s| String::from_str(s).unwrap()).collect();
-    let desired_patterns: Vec<String> = desired_patterns.split('\n').map(|s| String::from_str(s).unwrap()).collect();
+    return Ok(count_possible_patterns(&available_blocks, &desired_patterns));
+}
 
+pub fn count_possible_patterns(available_blocks: &Vec<String>, desired_patterns: &Vec<String>) -> i32
+{
     let mut num_possible = 0;
     for desired_pattern in desired_patterns.iter()
     {
-        if pattern_possible(desired_pattern, &available_blocks)
+        if pattern_possible(desired_pattern, available_blocks)
         {
             num_possible += 1;
         }
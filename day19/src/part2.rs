@@ -4,22 +4,27 @@ use std::str::FromStr;
 use fxhash::FxHashSet;
 use fxhash::FxHashMap;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
 
-pub fn compute_answer(input: &String) -> u128
+use crate::towels::parse_towels;
+
+pub fn compute_answer(input: &String) -> Result<u128, ParseError>
 {
-    let (available_blocks, desired_patterns) = input.split_once("\n\n").unwrap();
+    let (available_blocks, desired_patterns) = parse_towels(input)?;
 
-    let available_blocks : Vec<String> = available_blocks.split(", ").map(|s| String::from_str(s).unwrap()).collect();
-    let desired_patterns: Vec<String> = desired_patterns.split('\n').map(|s| String::from_str(s).unwrap()).collect();
+    return Ok(count_pattern_arrangements(&available_blocks, &desired_patterns));
+}
 
+pub fn count_pattern_arrangements(available_blocks: &Vec<String>, desired_patterns: &Vec<String>) -> u128
+{
     let mut total_ways_possible = 0;
     for desired_pattern in desired_patterns.iter()
     {
-        let num_ways_possible = pattern_possible_count(desired_pattern, &available_blocks);
+        let num_ways_possible = pattern_possible_count(desired_pattern, available_blocks);
         if num_ways_possible > 0
         {
             total_ways_possible += num_ways_possible;
@@ -44,14 +49,14 @@ fn pattern_possible_count(desired: &String, available_blocks : &Vec<String>) ->
     }
 
     let mut count_cache: FxHashMap<usize, u128> = FxHashMap::default();
-    let num_matches = count_patterns(&desired, &culled_blocks, &mut count_cache);
+    let num_matches = count_patterns(desired, &culled_blocks, &mut count_cache);
 
     return num_matches;
 }
 
 fn count_patterns(desired_remain: &str, available_blocks : &Vec<&str>, count_cache: &mut FxHashMap<usize, u128>) -> u128
 {
-    assert!(desired_remain.len() != 0);
+    assert!(!desired_remain.is_empty());
 
     // Search cache for answer.
     if let Some(cached_count) = count_cache.get(&desired_remain.len())
@@ -0,0 +1,39 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+use crate::towels::parse_towels;
+
+pub struct Day19;
+
+impl Solution for Day19
+{
+    type Params = NoParams;
+    type Parsed1 = (Vec<String>, Vec<String>);
+    type Parsed2 = (Vec<String>, Vec<String>);
+
+    const DAY: u32 = 19;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return parse_towels(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        let (available_blocks, desired_patterns) = parsed;
+        return Answer::from(part1::count_possible_patterns(available_blocks, desired_patterns));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return parse_towels(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        let (available_blocks, desired_patterns) = parsed;
+        return Answer::from(part2::count_pattern_arrangements(available_blocks, desired_patterns));
+    }
+}
\ No newline at end of file
@@ -19,13 +19,13 @@ bbrgwb";
     fn part_1_test() 
     {
         let result = part1::compute_answer(&String::from(TEST_STR));
-        assert_eq!(result, 6);
+        assert_eq!(result, Ok(6));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = part2::compute_answer(&String::from(TEST_STR));
-        assert_eq!(result, 16);
+        assert_eq!(result, Ok(16));
     }
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use aoc_core::parse_error::*;
+
+const STRIPE_COLOURS: &str = "wubrg";
+
+// The available towel patterns and the designs we want to make from them.
+pub fn parse_towels(input: &str) -> Result<(Vec<String>, Vec<String>), ParseError>
+{
+    let sections = split_sections(input);
+    if sections.len() != 2
+    {
+        return Err(ParseError::new(1, 1, "expected the towels and the designs separated by a blank line"));
+    }
+
+    let (towels_line, towels_str) = sections[0];
+    let (designs_line, designs_str) = sections[1];
+
+    let mut available_blocks = Vec::new();
+    for (line_num, line) in numbered_lines(towels_str)
+    {
+        for towel in line.split(',')
+        {
+            let towel = towel.trim();
+            check_stripes(towels_line + line_num - 1, line, towel)?;
+            available_blocks.push(String::from(towel));
+        }
+    }
+
+    let mut desired_patterns = Vec::new();
+    for (line_num, line) in numbered_lines(designs_str)
+    {
+        let design = line.trim_end();
+        check_stripes(designs_line + line_num - 1, line, design)?;
+        desired_patterns.push(String::from(design));
+    }
+
+    return Ok((available_blocks, desired_patterns));
+}
+
+fn check_stripes(line_num: usize, line: &str, stripes: &str) -> Result<(), ParseError>
+{
+    if stripes.is_empty()
+    {
+        return Err(ParseError::at_token(line_num, line, stripes, "expected a pattern of stripes"));
+    }
+
+    if let Some((idx, c)) = stripes.char_indices().find(|(_, c)| !STRIPE_COLOURS.contains(*c))
+    {
+        return Err(ParseError::at_token(line_num, line, &stripes[idx..], format!("unknown stripe colour '{c}'")));
+    }
+
+    return Ok(());
+}
\ No newline at end of file
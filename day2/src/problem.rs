@@ -1,33 +1,33 @@
-const MAX_CHANGE : i32 = 3;
+use aoc_core::parse_error::*;
 
-pub fn count_safe_str(input: String) -> i32
+pub fn count_safe_str(input: String, max_change: i32) -> Result<i32, ParseError>
 {
-    return count_safe(parse_lists(&input));
+    return Ok(count_safe(&parse_lists(&input)?, max_change));
 }
 
-pub fn count_safe_damp_str(input: String) -> i32
+pub fn count_safe_damp_str(input: String, max_change: i32) -> Result<i32, ParseError>
 {
-    return count_safe_damp(parse_lists(&input));
+    return Ok(count_safe_damp(&parse_lists(&input)?, max_change));
 }
 
-fn count_safe(input: Vec<Vec<i32>>) -> i32
+pub fn count_safe(input: &Vec<Vec<i32>>, max_change: i32) -> i32
 {
-    return input.iter().filter(|&x| is_safe(&x)).count() as i32;
+    return input.iter().filter(|&x| is_safe(x, max_change)).count() as i32;
 }
 
-fn count_safe_damp(input: Vec<Vec<i32>>) -> i32
+pub fn count_safe_damp(input: &Vec<Vec<i32>>, max_change: i32) -> i32
 {
-    return input.iter().filter(|&x| is_safe_damp(&x)).count() as i32;
+    return input.iter().filter(|&x| is_safe_damp(x, max_change)).count() as i32;
 }
 
-fn is_safe_damp(list: &Vec<i32>) -> bool
+fn is_safe_damp(list: &Vec<i32>, max_change: i32) -> bool
 {
     for i in 0..list.len()
     {
         let mut reduce_list = list.clone();
         reduce_list.remove(i);
 
-        if is_safe(&reduce_list)
+        if is_safe(&reduce_list, max_change)
         {
             return true;
         }
@@ -36,12 +36,12 @@ fn is_safe_damp(list: &Vec<i32>) -> bool
     return false;
 }
 
-fn is_safe(list: &Vec<i32>) -> bool
+fn is_safe(list: &Vec<i32>, max_change: i32) -> bool
 {
-    return is_safe_incresing(&list) || is_safe_decreasing(&list);
+    return is_safe_incresing(list, max_change) || is_safe_decreasing(list, max_change);
 }
 
-fn is_safe_incresing(list: &Vec<i32>) -> bool
+fn is_safe_incresing(list: &Vec<i32>, max_change: i32) -> bool
 {    
     for i in 1..list.len()
     {
@@ -52,7 +52,7 @@ fn is_safe_incresing(list: &Vec<i32>) -> bool
             return false;
         }
 
-        if curr - prev > MAX_CHANGE
+        if curr - prev > max_change
         {
             return false;
         }
@@ -61,7 +61,7 @@ fn is_safe_incresing(list: &Vec<i32>) -> bool
     return true;
 }
 
-fn is_safe_decreasing(list: &Vec<i32>) -> bool
+fn is_safe_decreasing(list: &Vec<i32>, max_change: i32) -> bool
 {
     for i in 1..list.len()
     {
@@ -72,7 +72,7 @@ fn is_safe_decreasing(list: &Vec<i32>) -> bool
             return false;
         }
 
-        if prev - curr > MAX_CHANGE
+        if prev - curr > max_change
         {
             return false;
         }
@@ -81,19 +81,17 @@ fn is_safe_decreasing(list: &Vec<i32>) -> bool
     return true;
 }
 
-fn parse_lists(input: &String) -> Vec<Vec<i32>>
+pub fn parse_lists(input: &String) -> Result<Vec<Vec<i32>>, ParseError>
 {
-    let lines : Vec<String> = input.lines().map(String::from).collect();
-
     let mut result : Vec<Vec<i32>> = Vec::new();
 
-    for line in lines.iter()
+    for (line_num, line) in numbered_lines(input)
     {
         let numbers : Vec<&str> = line.split_whitespace().collect();
-        let numbers : Vec<i32> = numbers.iter().map(|line| line.parse().unwrap()).collect();
+        let numbers : Vec<i32> = numbers.iter().map(|num| parse_token(line_num, line, num)).collect::<Result<_, _>>()?;
 
         result.push(numbers);
     }
 
-    return result;
+    return Ok(result);
 }
\ No newline at end of file
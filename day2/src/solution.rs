@@ -0,0 +1,57 @@
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day2Params
+{
+    // Biggest step between levels that still counts as safe.
+    #[serde(rename = "max_change")]
+    pub m_max_change: i32,
+}
+
+impl Default for Day2Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_max_change: 3,
+        };
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2
+{
+    type Params = Day2Params;
+    type Parsed1 = Vec<Vec<i32>>;
+    type Parsed2 = Vec<Vec<i32>>;
+
+    const DAY: u32 = 2;
+
+    fn parse_part1(input: &String, _params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return problem::parse_lists(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, params: &Self::Params) -> Answer
+    {
+        return Answer::from(problem::count_safe(parsed, params.m_max_change));
+    }
+
+    fn parse_part2(input: &String, _params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return problem::parse_lists(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        return Answer::from(problem::count_safe_damp(parsed, params.m_max_change));
+    }
+}
\ No newline at end of file
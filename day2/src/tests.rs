@@ -15,14 +15,14 @@ mod tests
     fn part_1_test() 
     {
 
-        let result = problem::count_safe_str(String::from(TEST_STR));
-        assert_eq!(result, 2);
+        let result = problem::count_safe_str(String::from(TEST_STR), 3);
+        assert_eq!(result, Ok(2));
     }
 
     #[test]
     fn part_2_test() 
     {
-        let result = problem::count_safe_damp_str(String::from(TEST_STR));
-        assert_eq!(result, 4);
+        let result = problem::count_safe_damp_str(String::from(TEST_STR), 3);
+        assert_eq!(result, Ok(4));
     }
 }
\ No newline at end of file
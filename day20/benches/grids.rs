@@ -0,0 +1,26 @@
+use std::fs;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_core::byte_grid::ByteGrid;
+
+use day20::part2;
+
+// The same flat index shortcut scan on a CharGrid and on a ByteGrid, with part 2's cheat length.
+pub fn criterion_benchmark(c: &mut Criterion)
+{
+    // Read once so the disk isn't part of what we measure.
+    let input = fs::read_to_string("./input.txt").expect("Should have been able to read the file");
+    let (grid, start, end) = part2::parse_track(&input).expect("Should have been able to parse the input");
+
+    let bytes = ByteGrid::from_char_grid(&grid);
+
+    let mut group = c.benchmark_group("Shortcuts");
+    group.sample_size(10);
+    group.bench_function("CharGrid", |b| b.iter(|| part2::count_long_shortcuts_flat(black_box(&grid), '.', '#', start, end, 100, 20)));
+    group.bench_function("ByteGrid", |b| b.iter(|| part2::count_long_shortcuts_flat(black_box(&bytes), b'.', b'#', start, end, 100, 20)));
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
\ No newline at end of file
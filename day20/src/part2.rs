@@ -1,12 +1,16 @@
 use glam::IVec2;
 use strum::IntoEnumIterator;
-use std::collections::BinaryHeap;
-use fxhash::{FxHashMap, FxHashSet};
-
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use std::collections::VecDeque;
+use fxhash::FxHashSet;
+
+use aoc_core::int_grid::*;
+use aoc_core::byte_grid::ByteGrid;
+use aoc_core::char_grid::*;
+use aoc_core::grid::Grid;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
+use aoc_core::search::{bfs, GridWalk, SearchResult};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 struct Shortcut
@@ -17,66 +21,36 @@ struct Shortcut
     saving: usize
 }
 
-/// A node in the priority queue
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct Node
+pub fn compute_answer(input: &String, min_shortcut: usize, shortcut_len: i32) -> Result<usize, ParseError>
 {
-    position: IVec2,
-    cost: usize,
-}
+    let (grid, start_pos, end_pos) = parse_track(input)?;
 
-impl Ord for Node
-{
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering
-    {
-        // Reverse the comparison to make BinaryHeap a min-heap
-        other.cost.cmp(&self.cost)
-    }
+    return Ok(count_long_shortcuts(&grid, start_pos, end_pos, min_shortcut, shortcut_len));
 }
 
-impl PartialOrd for Node
+// The track with S and E turned back into open track.
+pub fn parse_track(input: &String) -> Result<(CharGrid, IVec2, IVec2), ParseError>
 {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
-    {
-        Some(self.cmp(other))
-    }
-}
+    let mut grid = CharGrid::parse(input)?;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
-struct PathInfo
-{
-    costs: FxHashMap<IVec2, usize>,
-    came_from: FxHashMap<IVec2, IVec2>
-}
-
-pub fn compute_answer(input: &String, min_shortcut: usize, shortcut_len: i32) -> usize
-{
-    let mut grid = CharGrid::from(&input);
-
-    let start_pos = grid.find_first('S').expect("Couldn't find start.");
-    let end_pos = grid.find_first('E').expect("Couldn't find end.");
+    let start_pos = grid.expect_unique('S', "start (S)")?;
+    let end_pos = grid.expect_unique('E', "end (E)")?;
     let _ = grid.set_v(start_pos, '.');
     let _ = grid.set_v(end_pos,'.');
 
+    return Ok((grid, start_pos, end_pos));
+}
+
+pub fn count_long_shortcuts(grid: &CharGrid, start_pos: IVec2, end_pos: IVec2, min_shortcut: usize, shortcut_len: i32) -> usize
+{
     // Find all distances to the end.
-    let to_end_path_info = dijkstra(end_pos, &grid);
-    let end_to_start_path = build_up_path(end_pos, start_pos, &to_end_path_info).expect("Can't path from end to start.");
+    let to_end_path_info = bfs(&GridWalk::from(grid, |c| *c == '.'), end_pos, |_| false);
+    let end_to_start_path = to_end_path_info.path_to(start_pos).expect("Can't path from end to start.");
     let mut points_on_path: FxHashSet<IVec2> = FxHashSet::default();
     points_on_path.extend(end_to_start_path.iter());
 
     // Now find shortcuts
-    let potential_shortcuts = find_potential_shrotcuts(shortcut_len, &grid, &to_end_path_info);
-
-    println!("Found {} potential", potential_shortcuts.len());
-
-    // for i in 0..potential_shortcuts.len()
-    // {
-    //     let mut grid_clone = grid.clone();
-    //     let _ = grid_clone.set_v(potential_shortcuts[i].start, 's');
-    //     let _ = grid_clone.set_v(potential_shortcuts[i].end, 'S');
-    //     println!("{}", grid_clone.to_string());
-    //     println!("\n\n\n");
-    // }
+    let potential_shortcuts = find_potential_shrotcuts(shortcut_len, grid, &to_end_path_info);
 
     let shortcuts_on_path : Vec<&Shortcut> = potential_shortcuts.iter().filter(|&s| points_on_path.contains(&s.start)).collect();
     assert!(shortcuts_on_path.len() == potential_shortcuts.len(), "Not all shortcuts are on the path, think how to filter those too?");
@@ -86,153 +60,140 @@ pub fn compute_answer(input: &String, min_shortcut: usize, shortcut_len: i32) ->
     return num_long_shortcuts;
 }
 
-fn find_potential_shrotcuts(shortcut_len: i32, grid: &CharGrid, to_end_path_info: &PathInfo) -> Vec<Shortcut>
+// The same count on a byte grid, which is what the solution uses.
+pub fn count_long_shortcuts_bytes(grid: &CharGrid, start_pos: IVec2, end_pos: IVec2, min_shortcut: usize, shortcut_len: i32) -> usize
 {
-    let mut shortcuts : Vec<Shortcut> = Vec::new();
+    return count_long_shortcuts_flat(&ByteGrid::from_char_grid(grid), b'.', b'#', start_pos, end_pos, min_shortcut, shortcut_len);
+}
 
-    for x in 0..grid.m_width
+// Pads the grid with a border as wide as the longest cheat, so every cheat end can be looked up
+// by index without a bounds check. The border is at least 1 wide so the BFS can step off any
+// edge of the track too. Generic over the cell type so benches/grids.rs can time the same
+// algorithm on a CharGrid and a ByteGrid.
+pub fn count_long_shortcuts_flat<T: Copy + PartialEq>(grid: &Grid<T>, track: T, wall: T, start_pos: IVec2, end_pos: IVec2, min_shortcut: usize, shortcut_len: i32) -> usize
+{
+    let padding = shortcut_len.max(1);
+    let grid = grid.padded(wall, padding);
+    let offset = IVec2::splat(padding);
+
+    // Distance to the end of every track cell, -1 for walls.
+    let mut to_end: Vec<i32> = vec![-1; grid.as_slice().len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    let end_idx = grid.flat_index(end_pos + offset);
+    to_end[end_idx] = 0;
+    queue.push_back(end_idx);
+
+    while let Some(idx) = queue.pop_front()
     {
-        for y in 0..grid.m_height
+        for step in grid.flat_offsets_4()
         {
-            let start = IVec2::new(x, y);
-            let char_at_start = grid.at_vec(start).unwrap();
-            if char_at_start != '.'
+            let next_idx = idx.wrapping_add_signed(step);
+            if grid.as_slice()[next_idx] == track && to_end[next_idx] < 0
             {
-                // Shortcut must start at empty space.
-                continue;
+                to_end[next_idx] = to_end[idx] + 1;
+                queue.push_back(next_idx);
             }
+        }
+    }
 
-            let start_dist = to_end_path_info.costs.get(&start);
-            if start_dist.is_none()
+    assert!(to_end[grid.flat_index(start_pos + offset)] >= 0, "Can't path from end to start.");
+
+    // Every cheat as an index offset with how long it takes.
+    let width = grid.m_width as isize;
+    let mut cheats: Vec<(isize, i32)> = Vec::new();
+    for dy in -shortcut_len..=shortcut_len
+    {
+        let dx_range = shortcut_len - dy.abs();
+        for dx in -dx_range..=dx_range
+        {
+            // Standing still isn't a cheat.
+            if dx == 0 && dy == 0
             {
-                // Start is infinite distance from path.
                 continue;
             }
-            let start_dist = *start_dist.unwrap();
-
-            // Scan all possible points we can tunnel to.
-            for dx in -shortcut_len..=shortcut_len
-            {
-                let dy_range = (dx.abs() - shortcut_len).abs();
-                for dy in -dy_range..=dy_range
-                {
-                    let delta = IVec2::new(dx, dy);
-                    let delta_cost = manhattan_size(delta);
-                    assert!(manhattan_size(delta) <= shortcut_len);
-
-                    let end = start + delta;
-
-                    if let Some(char_at_end) = grid.at_vec(end)
-                    {
-                        if char_at_end != '.'
-                        { 
-                            // Shortcut must end at empty space.
-                            continue;
-                        }
-                    }
-                    else
-                    {
-                        // Shortcut must end inside grid.
-                        continue;
-                    }
 
-                    let end_dist = to_end_path_info.costs.get(&end);
-                    if end_dist.is_none()
-                    {
-                        // Start is infinite distance from path.
-                        continue;
-                    }
-                    let end_dist = *end_dist.unwrap();
+            cheats.push((dy as isize * width + dx as isize, dx.abs() + dy.abs()));
+        }
+    }
 
-                    if end_dist + (delta_cost as usize) < start_dist
-                    {
-                        let saving = start_dist - (end_dist + (delta_cost as usize));
-                        let new_shortcut = Shortcut { start: start, end: end, length: delta_cost, saving: saving };
-                        shortcuts.push(new_shortcut);
-                    }
-                }
+    // A cheat has to save some time, even if `min_shortcut` is 0.
+    let min_saving = (min_shortcut as i32).max(1);
+    let mut num_long_shortcuts = 0;
+    for (idx, start_dist) in to_end.iter().enumerate().filter(|(_, dist)| **dist >= 0)
+    {
+        for (step, cost) in cheats.iter()
+        {
+            let end_dist = to_end[idx.wrapping_add_signed(*step)];
+            if end_dist >= 0 && end_dist + cost + min_saving <= *start_dist
+            {
+                num_long_shortcuts += 1;
             }
         }
     }
 
-    return shortcuts;
+    return num_long_shortcuts;
 }
 
-/// Dijkstra's algorithm on a 2D grid to find all shortest distances from start point
-/// - `start`: Starting point
-/// - `grid`: A grid to check if a tile is passable
-/// - `shortcuts`: Shortcuts to travel through
-fn dijkstra(start: IVec2, grid: &CharGrid) -> PathInfo
+fn find_potential_shrotcuts(shortcut_len: i32, grid: &CharGrid, to_end_path_info: &SearchResult<IVec2>) -> Vec<Shortcut>
 {
-    let mut open_set = BinaryHeap::new();
-    open_set.push(Node 
-    {
-        position: start,
-        cost: 0,
-    });
-
-    // Maps positions to their cheapest cost and the preceding node
-    let mut costs = FxHashMap::default();
-    let mut came_from = FxHashMap::default();
-
-    // Initial cost for the starting position
-    costs.insert(start, 0);
+    let mut shortcuts : Vec<Shortcut> = Vec::new();
 
-    while let Some(Node { position, cost }) = open_set.pop()
+    // Shortcut must start at empty space.
+    for start in grid.positions_where(|c| *c == '.')
     {
-        // Explore neighbors
-        for dir in Direction::iter()
+        let start_dist = to_end_path_info.cost(start);
+        if start_dist.is_none()
         {
-            let neighbor_pos = dir.add_to(position);
+            // Start is infinite distance from path.
+            continue;
+        }
+        let start_dist = start_dist.unwrap() as usize;
 
-            let char_at = grid.at_vec(neighbor_pos);
+        // Scan all possible points we can tunnel to.
+        for dx in -shortcut_len..=shortcut_len
+        {
+            let dy_range = (dx.abs() - shortcut_len).abs();
+            for dy in -dy_range..=dy_range
+            {
+                let delta = IVec2::new(dx, dy);
+                let delta_cost = manhattan_size(delta);
+                assert!(manhattan_size(delta) <= shortcut_len);
 
-            let passable = char_at == Some('.');
+                let end = start + delta;
 
-            if passable
-            {
-                let new_cost = cost + 1;
+                if let Some(char_at_end) = grid.at_vec(end)
+                {
+                    if char_at_end != '.'
+                    { 
+                        // Shortcut must end at empty space.
+                        continue;
+                    }
+                }
+                else
+                {
+                    // Shortcut must end inside grid.
+                    continue;
+                }
+
+                let end_dist = to_end_path_info.cost(end);
+                if end_dist.is_none()
+                {
+                    // Start is infinite distance from path.
+                    continue;
+                }
+                let end_dist = end_dist.unwrap() as usize;
 
-                if new_cost < *costs.get(&neighbor_pos).unwrap_or(&usize::MAX)
+                if end_dist + (delta_cost as usize) < start_dist
                 {
-                    costs.insert(neighbor_pos, new_cost);
-                    came_from.insert(neighbor_pos, position);
-                    open_set.push(Node
-                    {
-                        position: neighbor_pos,
-                        cost: new_cost,
-                    });
+                    let saving = start_dist - (end_dist + (delta_cost as usize));
+                    let new_shortcut = Shortcut { start, end, length: delta_cost, saving };
+                    shortcuts.push(new_shortcut);
                 }
             }
         }
     }
 
-    return PathInfo{ costs: costs, came_from: came_from };
-}
-
-fn build_up_path(start: IVec2, end: IVec2, path_info: &PathInfo) -> Option<Vec<IVec2>>
-{
-    assert!(*path_info.costs.get(&start).unwrap() == 0, "Starting at non-zero point? Wrong path info");
-
-    if path_info.came_from.get(&end).is_none()
-    {
-        return None;
-    }
-
-    let mut result = Vec::new();
-    let mut curr = end.clone();
-
-    // Build path backwards
-    while curr != start
-    {
-        result.push(curr);
-        curr = *path_info.came_from.get(&curr).unwrap();
-    }
-
-    result.push(start);
-    result.reverse();
-
-    return Some(result);
+    return shortcuts;
 }
 
 fn manhattan_size(vec: IVec2) -> i32
@@ -0,0 +1,69 @@
+use glam::IVec2;
+
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+use aoc_core::char_grid::CharGrid;
+
+use crate::{part1, part2};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day20Params
+{
+    // Only shortcuts saving at least this many picoseconds count.
+    #[serde(rename = "min_shortcut")]
+    pub m_min_shortcut: usize,
+    // How long a cheat can last in each part.
+    #[serde(rename = "part1_cheat_len")]
+    pub m_part1_cheat_len: i32,
+    #[serde(rename = "part2_cheat_len")]
+    pub m_part2_cheat_len: i32,
+}
+
+impl Default for Day20Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_min_shortcut: 100,
+            m_part1_cheat_len: 2,
+            m_part2_cheat_len: 20,
+        };
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20
+{
+    type Params = Day20Params;
+    type Parsed1 = (CharGrid, IVec2, IVec2);
+    type Parsed2 = (CharGrid, IVec2, IVec2);
+
+    const DAY: u32 = 20;
+
+    fn parse_part1(input: &String, _params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return part2::parse_track(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, params: &Self::Params) -> Answer
+    {
+        let (grid, start, end) = parsed;
+        return Answer::from(part2::count_long_shortcuts_bytes(grid, *start, *end, params.m_min_shortcut, params.m_part1_cheat_len));
+    }
+
+    fn parse_part2(input: &String, _params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return part2::parse_track(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        let (grid, start, end) = parsed;
+        return Answer::from(part2::count_long_shortcuts_bytes(grid, *start, *end, params.m_min_shortcut, params.m_part2_cheat_len));
+    }
+}
\ No newline at end of file
@@ -23,21 +23,34 @@ mod tests
     #[test]
     fn part_1_test() 
     {
-        assert_eq!(44, part1::compute_answer(&String::from(TEST_STR), 2));
-        assert_eq!(30, part1::compute_answer(&String::from(TEST_STR), 4));
-        assert_eq!(16, part1::compute_answer(&String::from(TEST_STR), 6));
-        assert_eq!(14, part1::compute_answer(&String::from(TEST_STR), 8));
-        assert_eq!(1, part1::compute_answer(&String::from(TEST_STR), 64));
+        assert_eq!(Ok(44), part1::compute_answer(&String::from(TEST_STR), 2));
+        assert_eq!(Ok(30), part1::compute_answer(&String::from(TEST_STR), 4));
+        assert_eq!(Ok(16), part1::compute_answer(&String::from(TEST_STR), 6));
+        assert_eq!(Ok(14), part1::compute_answer(&String::from(TEST_STR), 8));
+        assert_eq!(Ok(1), part1::compute_answer(&String::from(TEST_STR), 64));
     }
 
     #[test]
     fn part_2_test() 
     {
         const PART_1_SHORTCUT_LEN : i32 = 2;
-        assert_eq!(44, part2::compute_answer(&String::from(TEST_STR), 2, PART_1_SHORTCUT_LEN));
-        assert_eq!(30, part2::compute_answer(&String::from(TEST_STR), 4, PART_1_SHORTCUT_LEN));
-        assert_eq!(16, part2::compute_answer(&String::from(TEST_STR), 6, PART_1_SHORTCUT_LEN));
-        assert_eq!(14, part2::compute_answer(&String::from(TEST_STR), 8, PART_1_SHORTCUT_LEN));
-        assert_eq!(1,  part2::compute_answer(&String::from(TEST_STR), 64, PART_1_SHORTCUT_LEN));
+        assert_eq!(Ok(44), part2::compute_answer(&String::from(TEST_STR), 2, PART_1_SHORTCUT_LEN));
+        assert_eq!(Ok(30), part2::compute_answer(&String::from(TEST_STR), 4, PART_1_SHORTCUT_LEN));
+        assert_eq!(Ok(16), part2::compute_answer(&String::from(TEST_STR), 6, PART_1_SHORTCUT_LEN));
+        assert_eq!(Ok(14), part2::compute_answer(&String::from(TEST_STR), 8, PART_1_SHORTCUT_LEN));
+        assert_eq!(Ok(1),  part2::compute_answer(&String::from(TEST_STR), 64, PART_1_SHORTCUT_LEN));
+
+        let (grid, start, end) = part2::parse_track(&String::from(TEST_STR)).unwrap();
+        assert_eq!(44, part2::count_long_shortcuts_bytes(&grid, start, end, 2, PART_1_SHORTCUT_LEN));
+        assert_eq!(285, part2::count_long_shortcuts_bytes(&grid, start, end, 50, 20));
+        assert_eq!(285, part2::count_long_shortcuts_flat(&grid, '.', '#', start, end, 50, 20));
+
+        // Cheats that save nothing don't count, so both versions agree with no minimum too.
+        for shortcut_len in [0, 1, 2, 20]
+        {
+            let expected = part2::count_long_shortcuts(&grid, start, end, 0, shortcut_len);
+            assert_eq!(expected, part2::count_long_shortcuts_bytes(&grid, start, end, 0, shortcut_len), "shortcut_len {shortcut_len}");
+        }
+        assert_eq!(0, part2::count_long_shortcuts_bytes(&grid, start, end, 0, 1));
     }
 }
\ No newline at end of file
@@ -1,9 +1,22 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::path::Path;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_core::config::{load_params, CONFIG_FILE};
+use day20_unity::{parse_track, part2, Day20UnityParams};
 
 pub fn criterion_benchmark(c: &mut Criterion)
 {
-    //c.bench_function("Part 1", |b| b.iter(|| day20_unity::run_part1()));
-    c.bench_function("Part 2", |b| b.iter(|| day20_unity::run_part2()));
+    // Read once so the disk isn't part of what we measure.
+    let input = fs::read_to_string("./input.txt").expect("Should have been able to read the file");
+    let params : Day20UnityParams = load_params(Path::new(CONFIG_FILE)).expect("Should have been able to read the config");
+
+    let (grid, end_pos) = parse_track(&input).expect("Should have been able to parse the track");
+
+    c.bench_function("Parse", |b| b.iter(|| parse_track(black_box(&input))));
+    //c.bench_function("Part 1", |b| b.iter(|| part1::run(&input, params.m_part1_shortcut_len, params.m_min_shortcut)));
+    c.bench_function("Part 2", |b| b.iter(|| part2::solve(black_box(&grid), end_pos, params.m_part2_shortcut_len, params.m_min_shortcut)));
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,7 +1,83 @@
-mod part1;
-mod part2;
+pub mod part1;
+pub mod part2;
 
 use std::fs;
+use std::path::Path;
+
+use glam::IVec2;
+use serde::Deserialize;
+
+use aoc_core::config::{load_params, CONFIG_FILE};
+use aoc_core::grid::Grid;
+use aoc_core::parse_error::{unwrap_or_exit, ParseError};
+use aoc_core::tile_grid::Tile;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day20UnityParams
+{
+    // Only shortcuts saving at least this many picoseconds count.
+    #[serde(rename = "min_shortcut")]
+    pub m_min_shortcut: i32,
+    // How long a cheat can last in each part.
+    #[serde(rename = "part1_shortcut_len")]
+    pub m_part1_shortcut_len: i32,
+    #[serde(rename = "part2_shortcut_len")]
+    pub m_part2_shortcut_len: i32,
+}
+
+impl Default for Day20UnityParams
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_min_shortcut: 100,
+            m_part1_shortcut_len: 2,
+            m_part2_shortcut_len: 20,
+        };
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackTile
+{
+    Track,
+    Wall,
+}
+
+impl Tile for TrackTile
+{
+    fn from_char(c: char) -> Option<Self>
+    {
+        match c
+        {
+            '.' => { return Some(TrackTile::Track); }
+            '#' => { return Some(TrackTile::Wall); }
+            _ => { return None; }
+        }
+    }
+
+    fn to_char(&self) -> char
+    {
+        match self
+        {
+            TrackTile::Track => { return '.'; }
+            TrackTile::Wall => { return '#'; }
+        }
+    }
+}
+
+// Walls are -1 and the track is 0 until find_path fills in the distances, with the end position.
+// Both parts only walk back from the end, but the start still has to be there exactly once.
+pub fn parse_track(input: &str) -> Result<(Grid<i32>, IVec2), ParseError>
+{
+    let (track, markers) = Grid::<TrackTile>::parse_tiles_with_markers(input, &['S', 'E'], TrackTile::Track)?;
+    markers.expect_unique(&['S'], "start (S)")?;
+    let (end, _) = markers.expect_unique(&['E'], "end (E)")?;
+
+    return Ok((track.map(|tile| if *tile == TrackTile::Wall { -1 } else { 0 }), end));
+}
 
 fn read_all_lines(path : String) -> String
 {
@@ -14,7 +90,9 @@ pub fn run_part2() -> i64
     let file_path : String = String::from("./input.txt");
     let file_contents = read_all_lines(file_path);
 
-    let part2 = part2::run(&file_contents);
+    let params : Day20UnityParams = unwrap_or_exit(load_params(Path::new(CONFIG_FILE)));
+
+    let part2 = unwrap_or_exit(part2::run(&file_contents, params.m_part2_shortcut_len, params.m_min_shortcut));
     return part2;
 }
 
@@ -23,6 +101,8 @@ pub fn run_part1() -> i64
     let file_path : String = String::from("./input.txt");
     let file_contents = read_all_lines(file_path);
 
-    let part1 = part1::run(&file_contents);
+    let params : Day20UnityParams = unwrap_or_exit(load_params(Path::new(CONFIG_FILE)));
+
+    let part1 = unwrap_or_exit(part1::run(&file_contents, params.m_part1_shortcut_len, params.m_min_shortcut));
     return part1;
 }
\ No newline at end of file
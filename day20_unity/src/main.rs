@@ -1,6 +1,3 @@
-mod part2;
-mod part1;
-
 fn main() 
 {
     let part1 = day20_unity::run_part1();
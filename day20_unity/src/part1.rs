@@ -3,99 +3,61 @@
 #![allow(unused_imports)]
 
 use std::cmp;
+use std::path::Path;
 
 use glam::IVec2;
 use rayon::prelude::*;
 
-const GRID_SIZE : i32 = 141;
+use aoc_core::parse_error::ParseError;
+use aoc_core::grid::Grid;
 
+use crate::parse_track;
 
-pub struct Grid
+pub fn run(input: &str, shortcut_len: i32, min_shortcut: i32) -> Result<i64, ParseError>
 {
-    m_walls: Vec<i32>
+    let (grid, end_pos) = parse_track(input)?;
+
+    return Ok(solve(&grid, end_pos, shortcut_len, min_shortcut));
 }
 
-impl Grid
+pub fn solve(grid: &Grid<i32>, end_pos: IVec2, shortcut_len: i32, min_shortcut: i32) -> i64
 {
-    pub fn from(input : &str) -> (Self, IVec2) 
-    {
-        let mut end = IVec2::ZERO;
-
-        let mut walls: Vec<i32> = Vec::with_capacity((GRID_SIZE * GRID_SIZE) as usize);
-
-        for c in input.chars().filter(|c| *c != '\n' && *c != '\r').enumerate()
-        {
-            walls.push(if c.1 == '#' { -1 } else { 0 });
-            
-            if c.1 == 'E'
-            {
-                let idx = c.0 as i32;
-                end = IVec2::new(idx % GRID_SIZE, idx / GRID_SIZE);
-            }
-        }
-
-        let this = Self
-        {
-            m_walls: walls
-        };
+    let mut grid = grid.clone();
 
-        return (this, end);
-    }
-
-    pub fn get_unchecked_vec(&self, pos: IVec2) -> i32
-    {
-        let idx = pos.x + pos.y * GRID_SIZE;
-        return self.m_walls[idx as usize];
-    }
-
-    pub fn set_v(&mut self, pos: IVec2, value: i32)
-    {
-        self.set(pos.x, pos.y, value);
-    }
-
-    pub fn set(&mut self, x: i32, y: i32, value: i32)
-    {
-        let idx = x + y * GRID_SIZE;
-        self.m_walls[idx as usize] = value;
-    }
+    // Find all distances to the end.
+    find_path(end_pos, &mut grid);
 
-    pub fn inside_grid(&self, x: i32, y: i32) -> bool
-    {
-        return 0 <= x && x < GRID_SIZE && 0 <= y && y < GRID_SIZE;
-    }
+    return find_potential_shrotcuts(&grid, shortcut_len, min_shortcut);
 }
 
-const SHORTCUT_LEN : i32 = 2;
-const MIN_SHORTCUT : i32 = 100;
-
-pub fn run(input: &str) -> i64
+// The distance to the end of every track cell as a heatmap image.
+pub fn write_distance_heatmap(input: &str, path: &Path, scale: u32) -> Result<(), String>
 {
-    let (mut grid, end_pos) = Grid::from(&input);
-
-    // Find all distances to the end.
+    let (mut grid, end_pos) = parse_track(input).map_err(|err| err.to_string())?;
     find_path(end_pos, &mut grid);
 
-    return find_potential_shrotcuts(&grid);
+    return grid.write_heatmap(path, scale);
 }
 
-fn find_potential_shrotcuts(grid: &Grid) -> i64
+fn find_potential_shrotcuts(grid: &Grid<i32>, shortcut_len: i32, min_shortcut: i32) -> i64
 {
-    return (1..(GRID_SIZE-1))
+    return (1..(grid.m_width-1))
         .into_par_iter()
         .map(|x| 
             {
-                (1..(GRID_SIZE-1))
-                    .map(|y| find_shortcuts_at(IVec2::new(x, y), grid))
+                (1..(grid.m_height-1))
+                    .map(|y| find_shortcuts_at(IVec2::new(x, y), grid, shortcut_len, min_shortcut))
                     .sum::<i16>() as i64
             })
         .sum();
 }
 
-fn find_shortcuts_at(start: IVec2, grid: &Grid) -> i16
+fn find_shortcuts_at(start: IVec2, grid: &Grid<i32>, shortcut_len: i32, min_shortcut: i32) -> i16
 {
-    let start_dist = grid.get_unchecked_vec(start);
+    let cells = grid.as_slice();
+    let start_dist = cells[grid.flat_index(start)];
 
-    if start_dist < MIN_SHORTCUT + SHORTCUT_LEN
+    if start_dist < min_shortcut + shortcut_len
     {
         // Shortcut must start at empty space.
         return 0;
@@ -104,9 +66,9 @@ fn find_shortcuts_at(start: IVec2, grid: &Grid) -> i16
     let mut num_shortcuts : i16 = 0;
 
     // Scan all possible points we can tunnel to.
-    for dx in [-SHORTCUT_LEN, SHORTCUT_LEN]
+    for dx in [-shortcut_len, shortcut_len]
     {
-        if dx + start.x < 0 || dx + start.x >= GRID_SIZE
+        if dx + start.x < 0 || dx + start.x >= grid.m_width
         {
             continue;
         }
@@ -114,21 +76,21 @@ fn find_shortcuts_at(start: IVec2, grid: &Grid) -> i16
         let mut end = start;
         end.x += dx;
 
-        let end_dist = grid.get_unchecked_vec(end);
+        let end_dist = cells[grid.flat_index(end)];
         if end_dist == -1
         {
             continue;
         }
 
-        if start_dist >= MIN_SHORTCUT + SHORTCUT_LEN + end_dist 
+        if start_dist >= min_shortcut + shortcut_len + end_dist 
         {
             num_shortcuts += 1;
         }
     }
 
-    for dy in [-SHORTCUT_LEN, SHORTCUT_LEN]
+    for dy in [-shortcut_len, shortcut_len]
     {
-        if dy + start.y < 0 || dy + start.y >= GRID_SIZE
+        if dy + start.y < 0 || dy + start.y >= grid.m_height
         {
             continue;
         }
@@ -136,13 +98,13 @@ fn find_shortcuts_at(start: IVec2, grid: &Grid) -> i16
         let mut end = start;
         end.y += dy;
 
-        let end_dist = grid.get_unchecked_vec(end);
+        let end_dist = cells[grid.flat_index(end)];
         if end_dist == -1
         {
             continue;
         }
 
-        if start_dist >= MIN_SHORTCUT + SHORTCUT_LEN + end_dist 
+        if start_dist >= min_shortcut + shortcut_len + end_dist 
         {
             num_shortcuts += 1;
         }
@@ -151,10 +113,10 @@ fn find_shortcuts_at(start: IVec2, grid: &Grid) -> i16
     return num_shortcuts;
 }
 
-fn find_path(start: IVec2, grid: &mut Grid)
+fn find_path(start: IVec2, grid: &mut Grid<i32>)
 {
     // Initial cost for the starting position
-    grid.set_v(start, 0);
+    grid[start] = 0;
 
     let mut len = 0;
     let mut prev = start;
@@ -166,7 +128,7 @@ fn find_path(start: IVec2, grid: &mut Grid)
         for dir in [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y]
         {
             let next = curr + dir;
-            if grid.get_unchecked_vec(next) == -1
+            if grid.as_slice()[grid.flat_index(next)] == -1
             {
                 continue;
             }
@@ -176,12 +138,13 @@ fn find_path(start: IVec2, grid: &mut Grid)
                 prev = curr;
                 curr = next;
                 len += 1;
-                grid.set_v(curr, len);
+                let idx = grid.flat_index(curr);
+                grid.as_mut_slice()[idx] = len;
                 break;
             }
         }
 
-        if any_neigh == false
+        if !any_neigh
         {
             break;
         }
@@ -0,0 +1,46 @@
+use aoc_core::parse_error::*;
+
+const NUMPAD_KEYS: &str = "0123456789A";
+
+// A code as typed on the numpad, and the number its digits make.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DoorCode
+{
+    pub m_keys: String,
+    pub m_value: i64,
+}
+
+// One code per line, made only of keys that exist on the door's numpad.
+pub fn parse_door_codes(input: &str) -> Result<Vec<DoorCode>, ParseError>
+{
+    let mut result = Vec::new();
+
+    for (line_num, line) in numbered_lines(input)
+    {
+        let code = line.trim_end();
+        if code.is_empty()
+        {
+            continue;
+        }
+
+        if let Some((idx, c)) = code.char_indices().find(|(_, c)| !NUMPAD_KEYS.contains(*c))
+        {
+            return Err(ParseError::at_token(line_num, line, &code[idx..], format!("'{c}' isn't a key on the numpad")));
+        }
+
+        let digits: String = code.chars().filter(|c| c.is_ascii_digit()).collect();
+        if digits.is_empty()
+        {
+            return Err(ParseError::new(line_num, 1, "code has no numeric part"));
+        }
+
+        let Ok(value) = digits.parse::<i64>() else
+        {
+            return Err(ParseError::at_token(line_num, line, code, "numeric part is too big"));
+        };
+
+        result.push(DoorCode { m_keys: code.to_string(), m_value: value });
+    }
+
+    return Ok(result);
+}
\ No newline at end of file
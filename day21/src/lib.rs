@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_imports)]
+
+mod tests;
+pub mod part1;
+pub mod part2;
+pub mod solution;
+pub mod door_codes;
\ No newline at end of file
@@ -1,13 +1,15 @@
 use std::cmp;
-use std::i32;
 use std::vec;
 
 use glam::IVec2;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
+
+use crate::door_codes::{parse_door_codes, DoorCode};
 
 const MIDDLE_ROBOTS : i64 = 2;
 
@@ -21,25 +23,25 @@ pub enum RobotCmd
     Press,
 }
 
-pub fn compute_answer(input: &String) -> i64
+pub fn compute_answer(input: &String) -> Result<i64, ParseError>
 {
-    return input.lines().map(|s| compute_line(s)).sum();
+    let codes = parse_door_codes(input)?;
+
+    return Ok(sum_complexities(&codes));
 }
 
-fn parse_numeric(input: &str) -> i64
+pub fn sum_complexities(codes: &Vec<DoorCode>) -> i64
 {
-    let numeric_part: String = input.chars().filter(|c| c.is_ascii_digit()).collect();
-    return numeric_part.parse::<i64>().unwrap();
+    return codes.iter().map(compute_line).sum();
 }
 
-pub fn compute_line(line: &str) -> i64
+pub fn compute_line(code: &DoorCode) -> i64
 {
     const FIND_ALL_VALUES : bool = true;
     
-    let line_value = parse_numeric(line);
-    println!("{} = {}", line, line_value);
+    let line_value = code.m_value;
 
-    let mut possible_computes : Vec<Vec<RobotCmd>> = get_numpad_moves(line, FIND_ALL_VALUES);
+    let mut possible_computes : Vec<Vec<RobotCmd>> = get_numpad_moves(&code.m_keys, FIND_ALL_VALUES);
 
     for i in 0..MIDDLE_ROBOTS
     {
@@ -248,7 +250,7 @@ fn push_robot_commands(start: IVec2, end: IVec2, horiz_first: bool, list: &mut V
 
 fn get_cmd_list_debug_str(cmds: &Vec<RobotCmd>) -> String
 {
-    cmds.into_iter()
+    cmds.iter()
         .map(|cmd| match cmd {
             RobotCmd::Up => '^',
             RobotCmd::Down => 'v',
@@ -1,17 +1,17 @@
 use std::cmp;
 use std::cmp::max;
 use std::collections::HashMap;
-use std::i32;
-use std::i64;
-use std::usize;
 use std::vec;
 
 use glam::IVec2;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::ParseError;
+
+use crate::door_codes::{parse_door_codes, DoorCode};
 
 type RobotSeq = Vec<RobotCmd>;
 type SearchCache = HashMap<(RobotSeq, i32), i64>;
@@ -26,30 +26,30 @@ pub enum RobotCmd
     Press,
 }
 
-pub fn compute_answer(input: &String, max_depth: i32) -> i64
+pub fn compute_answer(input: &String, max_depth: i32) -> Result<i64, ParseError>
 {
-    return input.lines().map(|s| compute_line(s, max_depth)).sum();
+    let codes = parse_door_codes(input)?;
+
+    return Ok(sum_complexities(&codes, max_depth));
 }
 
-fn parse_numeric(input: &str) -> i64
+pub fn sum_complexities(codes: &Vec<DoorCode>, max_depth: i32) -> i64
 {
-    let numeric_part: String = input.chars().filter(|c| c.is_ascii_digit()).collect();
-    return numeric_part.parse::<i64>().unwrap();
+    return codes.iter().map(|code| compute_line(code, max_depth)).sum();
 }
 
-pub fn compute_line(line: &str, max_depth: i32) -> i64
+pub fn compute_line(code: &DoorCode, max_depth: i32) -> i64
 {
-    let line_value = parse_numeric(line);
-    println!("{} = {}", line, line_value);
+    let line_value = code.m_value;
 
-    let numpad_seqs : Vec<RobotSeq> = get_all_numpad_seq(line);
+    let numpad_seqs : Vec<RobotSeq> = get_all_numpad_seq(&code.m_keys);
 
     let mut min_moves = i64::MAX;
     for numpad_seq in numpad_seqs.iter()
     {
         let mut cache = SearchCache::new();
         let mut sum_moves = 0;
-        let seq_split = split_seq_by_a_press(&numpad_seq);
+        let seq_split = split_seq_by_a_press(numpad_seq);
         for sub_seq in seq_split.iter()
         {
             sum_moves += num_keypad_moves_from_a(sub_seq, 0, max_depth, &mut cache);
@@ -57,7 +57,6 @@ pub fn compute_line(line: &str, max_depth: i32) -> i64
 
         min_moves = cmp::min(sum_moves, min_moves);
     }
-    println!("  min: {}", min_moves);
     
     return min_moves * line_value;
 }
@@ -239,7 +238,7 @@ fn push_robot_commands(start: IVec2, end: IVec2, horiz_first: bool, list: &mut R
 
 
 ///////////////////////////////////////////////
-/// Utils
+// Utils
 ///////////////////////////////////////////////
 
 // +---+---+---+
@@ -308,7 +307,7 @@ fn split_seq_by_a_press(seq: &RobotSeq) -> Vec<RobotSeq>
 
 fn get_cmd_list_debug_str(cmds: &Vec<RobotCmd>) -> String
 {
-    cmds.into_iter()
+    cmds.iter()
         .map(|cmd| match cmd {
             RobotCmd::Up => '^',
             RobotCmd::Down => 'v',
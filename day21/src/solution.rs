@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+use crate::door_codes::{parse_door_codes, DoorCode};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day21Params
+{
+    // Robots between you and the door in part 2.
+    #[serde(rename = "max_depth")]
+    pub m_max_depth: i32,
+}
+
+impl Default for Day21Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_max_depth: 24,
+        };
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21
+{
+    type Params = Day21Params;
+    type Parsed1 = Vec<DoorCode>;
+    type Parsed2 = Vec<DoorCode>;
+
+    const DAY: u32 = 21;
+
+    fn parse_part1(input: &String, _params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return parse_door_codes(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &Self::Params) -> Answer
+    {
+        return Answer::from(part1::sum_complexities(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return parse_door_codes(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        return Answer::from(part2::sum_complexities(parsed, params.m_max_depth));
+    }
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests 
 {
+    use aoc_core::parse_error::ParseError;
+
     use crate::part1;
     use crate::part2;
 
@@ -14,13 +16,20 @@ mod tests
     fn part_1_test() 
     {
         let result = part1::compute_answer(&String::from(TEST_STR));
-        assert_eq!(result, 126384);
+        assert_eq!(result, Ok(126384));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = part2::compute_answer(&String::from(TEST_STR), 1);
-        assert_eq!(result, 126384);
+        assert_eq!(result, Ok(126384));
+    }
+
+    #[test]
+    fn code_too_big_test() 
+    {
+        let result = part1::compute_answer(&String::from("029A\n99999999999999999999A"));
+        assert_eq!(result, Err(ParseError::new(2, 1, "numeric part is too big")));
     }
 }
\ No newline at end of file
@@ -1,5 +1,5 @@
 
-const ALL_CMDS : [[i32; 4]; 130321] = 
+static ALL_CMDS : [[i32; 4]; 130321] = 
 [
     [-9, -9, -9, -9],
     [-9, -9, -9, -8],
@@ -1,13 +1,19 @@
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 
-const NUM_ITER : u64 = 2000;
+pub fn compute_answer(input: &String, num_iter: u64) -> Result<u64, ParseError>
+{
+    let all_seeds: Vec<u64> = one_num_per_line(input)?;
+
+    return Ok(sum_nth_random_numbers(&all_seeds, num_iter));
+}
 
-pub fn compute_answer(input: &String) -> u64
+pub fn sum_nth_random_numbers(all_seeds: &Vec<u64>, num_iter: u64) -> u64
 {
-    return input.lines().filter_map(|s| s.parse::<u64>().ok()).map(|n| compute_nth_random_number(n, NUM_ITER)).sum();
+    return all_seeds.iter().map(|n| compute_nth_random_number(*n, num_iter)).sum();
 }
 
 fn compute_nth_random_number(seed: u64, iter: u64) -> u64
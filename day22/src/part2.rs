@@ -1,40 +1,41 @@
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 use crate::money_cmds;
 
 use rayon::prelude::*;
 
-const NUM_ITER : usize = 2000;
+pub fn compute_answer(input: &String, num_iter: usize) -> Result<i32, ParseError>
+{
+    let all_seeds: Vec<i32> = one_num_per_line(input)?;
+
+    return Ok(most_bananas(&all_seeds, num_iter));
+}
 
-pub fn compute_answer(input: &String) -> i32
+pub fn most_bananas(all_seeds: &Vec<i32>, num_iter: usize) -> i32
 {
-    let all_seeds: Vec<i32> = input.lines().filter_map(|s| s.parse::<i32>().ok()).collect();
-    let all_sequences: Vec<Vec<i32>> = all_seeds.iter().map(|seed| compute_random_numbers(*seed, NUM_ITER)).collect();
+    let all_sequences: Vec<Vec<i32>> = all_seeds.iter().map(|seed| compute_random_numbers(*seed, num_iter)).collect();
     let all_sequences_delta: Vec<(Vec<i32>, Vec<i32>)> = all_sequences.iter().map(|seq: &Vec<i32>| (seq.clone(), compute_delta_sequence(seq))).collect();
     
 
     let mut most_money = 0;
-    let mut best_seq = None;
     for (i, money_cmd) in money_cmds::all_cmds_iter().enumerate()
     {
         let total_profit = all_sequences_delta.par_iter()
                         .map(|(seq, delta_seq)|
                         {
-                            compute_profit(seq, delta_seq, &money_cmd)
+                            compute_profit(seq, delta_seq, money_cmd)
                         })
                         .sum::<i32>();
 
         if total_profit > most_money
         {
             most_money = total_profit;
-            best_seq = Some(money_cmd);
         }
     }
 
-    println!("Best seq {:?}", best_seq.unwrap());
-    
     return most_money;
 }
 
@@ -90,9 +91,9 @@ fn compute_random_numbers(seed: i32, size: usize) -> Vec<i32>
         result.push(next_random_number(curr_num));
     }
 
-    for i in 0..result.len()
+    for num in result.iter_mut()
     {
-        result[i] = result[i] % 10;
+        *num %= 10;
     }
 
     return result;
@@ -100,18 +101,20 @@ fn compute_random_numbers(seed: i32, size: usize) -> Vec<i32>
 
 fn next_random_number(seed: i32) -> i32
 {
+    // Widen before mixing, the multiply steps don't fit in an i32.
+    let seed = seed as i64;
     let step1 = prune(mix(seed, seed * 64));
     let step2 = prune(mix(step1, step1 / 32));
     let step3 = prune(mix(step2, step2 * 2048));
-    return step3;
+    return step3 as i32;
 }
 
-fn mix(n1: i32, n2: i32) -> i32
+fn mix(n1: i64, n2: i64) -> i64
 {
     return n1 ^ n2;
 }
 
-fn prune(n: i32) -> i32
+fn prune(n: i64) -> i64
 {
     return n % 16777216;
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+use aoc_core::parse_error::one_num_per_line;
+
+use crate::{part1, part2};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day22Params
+{
+    // Secret numbers each buyer generates.
+    #[serde(rename = "num_iter")]
+    pub m_num_iter: usize,
+}
+
+impl Default for Day22Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_num_iter: 2000,
+        };
+    }
+}
+
+pub struct Day22;
+
+impl Solution for Day22
+{
+    type Params = Day22Params;
+    type Parsed1 = Vec<u64>;
+    type Parsed2 = Vec<i32>;
+
+    const DAY: u32 = 22;
+
+    fn parse_part1(input: &String, _params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return one_num_per_line(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, params: &Self::Params) -> Answer
+    {
+        return Answer::from(part1::sum_nth_random_numbers(parsed, params.m_num_iter as u64));
+    }
+
+    fn parse_part2(input: &String, _params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return one_num_per_line(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        return Answer::from(part2::most_bananas(parsed, params.m_num_iter));
+    }
+}
\ No newline at end of file
@@ -17,14 +17,14 @@ mod tests
     #[test]
     fn part_1_test() 
     {
-        let result = part1::compute_answer(&String::from(TEST_STR));
-        assert_eq!(result, 37327623);
+        let result = part1::compute_answer(&String::from(TEST_STR), 2000);
+        assert_eq!(result, Ok(37327623));
     }
 
     #[test]
     fn part_2_test() 
     {
-        let result = part2::compute_answer(&String::from(PROFIT_TEST_STR));
-        assert_eq!(result, 23);
+        let result = part2::compute_answer(&String::from(PROFIT_TEST_STR), 2000);
+        assert_eq!(result, Ok(23));
     }
 }
\ No newline at end of file
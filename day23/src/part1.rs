@@ -2,36 +2,48 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use itertools::Itertools;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 
 
-struct Graph
+pub struct Graph
 {
     pub neighbour_map: HashMap<String, HashSet<String>>,
 }
 
 impl Graph
 {
-    pub fn from(input: &str) -> Graph
+    pub fn from(input: &str) -> Result<Graph, ParseError>
     {
         let mut new_graph = Graph{ neighbour_map: HashMap::new() };
 
-        for line in input.lines()
+        for (line_num, line) in numbered_lines(input)
         {
-            if line.len() == 0
+            if line.is_empty()
             {
                 continue;
             }
 
-            let (n1, n2) = line.split_once("-").unwrap();
+            let Some((n1, n2)) = line.split_once("-") else
+            {
+                return Err(ParseError::new(line_num, 1, "expected a connection like kh-tc"));
+            };
+
+            for name in [n1, n2]
+            {
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric())
+                {
+                    return Err(ParseError::at_token(line_num, line, name, format!("'{name}' isn't a computer name")));
+                }
+            }
 
             new_graph.add_connection(n1, n2);
         }
 
-        return new_graph;
+        return Ok(new_graph);
     }
 
     fn add_node_if_not_present(&mut self, node: &str)
@@ -49,8 +61,8 @@ impl Graph
         self.add_node_if_not_present(node1);
         self.add_node_if_not_present(node2);
 
-        self.neighbour_map.get_mut(&node1.to_string()).unwrap().insert(node2.to_string());
-        self.neighbour_map.get_mut(&node2.to_string()).unwrap().insert(node1.to_string());
+        self.neighbour_map.get_mut(node1).unwrap().insert(node2.to_string());
+        self.neighbour_map.get_mut(node2).unwrap().insert(node1.to_string());
     }
 
     fn find_all_kn(&self, root: &String, n: usize) -> Vec<Vec<String>>
@@ -110,10 +122,15 @@ impl Graph
     }
 }
 
-pub fn compute_answer(input: &str) -> i32
+pub fn compute_answer(input: &str) -> Result<i32, ParseError>
 {
-    let graph = Graph::from(input);
+    let graph = Graph::from(input)?;
+
+    return Ok(count_t_triangles(&graph));
+}
 
+pub fn count_t_triangles(graph: &Graph) -> i32
+{
     let mut unique_matches = HashSet::new();
     for (node, _) in graph.neighbour_map.iter()
     {
@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+
+pub struct Day23;
+
+impl Solution for Day23
+{
+    type Params = NoParams;
+    type Parsed1 = part1::Graph;
+    type Parsed2 = part2::Graph;
+
+    const DAY: u32 = 23;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return part1::Graph::from(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part1::count_t_triangles(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return part2::Graph::from(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part2::find_password(parsed));
+    }
+}
\ No newline at end of file
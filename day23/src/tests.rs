@@ -41,13 +41,13 @@ td-yn";
     fn part_1_test() 
     {
         let result = part1::compute_answer(TEST_STR);
-        assert_eq!(result, 7);
+        assert_eq!(result, Ok(7));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = part2::compute_answer(TEST_STR);
-        assert_eq!(result, "co,de,ka,ta");
+        assert_eq!(result, Ok(String::from("co,de,ka,ta")));
     }
 }
\ No newline at end of file
@@ -1,13 +1,14 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-enum Operator
+pub enum Operator
 {
     XOR,
     OR,
@@ -16,14 +17,14 @@ enum Operator
 
 impl Operator
 {
-    pub fn from(input: &str) -> Self
+    pub fn from(line_num: usize, line: &str, input: &str) -> Result<Self, ParseError>
     {
         match input
         {
-            "XOR" => { return Operator::XOR; }
-            "OR" => { return Operator::OR; }
-            "AND" => { return Operator::AND; }
-            _ => { panic!("Invalid operator {input}"); }
+            "XOR" => { return Ok(Operator::XOR); }
+            "OR" => { return Ok(Operator::OR); }
+            "AND" => { return Ok(Operator::AND); }
+            _ => { return Err(ParseError::at_token(line_num, line, input, format!("unknown operator '{input}'"))); }
         }
     }
 
@@ -38,34 +39,44 @@ impl Operator
     }
 }
 
-struct Equation
+#[derive(Clone)]
+pub struct Equation
 {
-    left: String,
-    right: String,
-    operator: Operator,
-    result: String
+    pub left: String,
+    pub right: String,
+    pub operator: Operator,
+    pub result: String
 }
 
 impl Equation
 {
-    pub fn from(input: &str) -> Self
+    pub fn from(line_num: usize, input: &str) -> Result<Self, ParseError>
     {
-        let (operations, result) = input.split_once("->").unwrap(); 
+        const EXPECTED: &str = "expected a gate like 'x00 AND y00 -> z00'";
+
+        let Some((operations, result)) = input.split_once("->") else
+        {
+            return Err(ParseError::new(line_num, 1, EXPECTED));
+        };
         let result = String::from(result.trim());
 
-        let mut operations = operations.trim().split(" ");
+        let operations: Vec<&str> = operations.split_whitespace().collect();
+        if operations.len() != 3 || result.is_empty()
+        {
+            return Err(ParseError::new(line_num, 1, EXPECTED));
+        }
 
-        let left = String::from(operations.next().unwrap().trim());
-        let operator = Operator::from(operations.next().unwrap().trim());
-        let right = String::from(operations.next().unwrap().trim());
+        let left = String::from(operations[0]);
+        let operator = Operator::from(line_num, input, operations[1])?;
+        let right = String::from(operations[2]);
 
-        Self
+        return Ok(Self
         {
-            left: left,
-            right: right,
-            operator: operator,
-            result: result
-        }
+            left,
+            right,
+            operator,
+            result
+        });
     }
 
     pub fn attempt_solve(&self, known_values: &HashMap<String, bool>) -> Option<bool>
@@ -88,14 +99,29 @@ impl Equation
     }
 }
 
-pub fn compute_answer(input: &str) -> i64
+pub fn compute_answer(input: &str) -> Result<i64, ParseError>
+{
+    let (known_values, equations) = parse_wires(input)?;
+
+    return Ok(z_output(&known_values, &equations));
+}
+
+pub fn parse_wires(input: &str) -> Result<(HashMap<String, bool>, Vec<Equation>), ParseError>
+{
+    let sections = split_sections(input);
+    if sections.len() != 2
+    {
+        return Err(ParseError::new(1, 1, "expected the wire values and the gates separated by a blank line"));
+    }
+
+    return Ok((parse_init_bits(sections[0])?, parse_equations(sections[1])?));
+}
+
+// Runs the gates until every wire is known and reads the number off the z wires.
+pub fn z_output(init_values: &HashMap<String, bool>, gates: &Vec<Equation>) -> i64
 {
-    let (init_bits_str, gates_str) = input.split_once("\n\n").unwrap();
-    let mut known_values = parse_init_bits(init_bits_str);
-    let mut equations: Vec<Equation> = gates_str.lines()
-                                .filter(|l| l.len() > 0)
-                                .map(|l| Equation::from(l))
-                                .collect();
+    let mut known_values = init_values.clone();
+    let mut equations = gates.clone();
 
     while equation_solve_step(&mut known_values, &mut equations)
     {
@@ -114,8 +140,6 @@ pub fn compute_answer(input: &str) -> i64
         }
     }
 
-    println!("{:b}", final_val);
-
     return final_val;
 }
 
@@ -141,19 +165,31 @@ fn equation_solve_step(known_values: &mut HashMap<String, bool>, equations: &mut
 }
 
 // Parse
-fn parse_init_bits(init_bits_str: &str) -> HashMap<String, bool>
+fn parse_init_bits((first_line, init_bits_str): (usize, &str)) -> Result<HashMap<String, bool>, ParseError>
 {
     let mut known_vals = HashMap::new();
 
-    for line in init_bits_str.lines().filter(|l| l.len() > 0)
+    for (line_num, line) in numbered_lines(init_bits_str).filter(|(_, l)| !l.is_empty())
     {
-        let (symbol, num) = line.split_once(":").unwrap();
+        let line_num = first_line + line_num - 1;
+        let Some((symbol, num)) = line.split_once(":") else
+        {
+            return Err(ParseError::new(line_num, 1, "expected a wire value like 'x00: 1'"));
+        };
 
         let symbol = String::from(symbol);
-        let num = num.trim().parse::<i32>().unwrap() != 0;
+        let num = parse_token::<i32>(line_num, line, num.trim())? != 0;
 
         known_vals.insert(symbol, num);
     }
 
-    return known_vals;
+    return Ok(known_vals);
+}
+
+fn parse_equations((first_line, gates_str): (usize, &str)) -> Result<Vec<Equation>, ParseError>
+{
+    return numbered_lines(gates_str)
+                .filter(|(_, l)| !l.is_empty())
+                .map(|(line_num, l)| Equation::from(first_line + line_num - 1, l))
+                .collect();
 }
\ No newline at end of file
@@ -5,13 +5,14 @@ use std::mem::swap;
 
 use rand::Rng;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-enum Operator
+pub enum Operator
 {
     XOR,
     OR,
@@ -20,14 +21,14 @@ enum Operator
 
 impl Operator
 {
-    pub fn from(input: &str) -> Self
+    pub fn from(line_num: usize, line: &str, input: &str) -> Result<Self, ParseError>
     {
         match input
         {
-            "XOR" => { return Operator::XOR; }
-            "OR" => { return Operator::OR; }
-            "AND" => { return Operator::AND; }
-            _ => { panic!("Invalid operator {input}"); }
+            "XOR" => { return Ok(Operator::XOR); }
+            "OR" => { return Ok(Operator::OR); }
+            "AND" => { return Ok(Operator::AND); }
+            _ => { return Err(ParseError::at_token(line_num, line, input, format!("unknown operator '{input}'"))); }
         }
     }
 
@@ -43,34 +44,43 @@ impl Operator
 }
 
 #[derive(Clone)]
-struct Equation
+pub struct Equation
 {
-    left: String,
-    right: String,
-    operator: Operator,
-    result: String
+    pub left: String,
+    pub right: String,
+    pub operator: Operator,
+    pub result: String
 }
 
 impl Equation
 {
-    pub fn from(input: &str) -> Self
+    pub fn from(line_num: usize, input: &str) -> Result<Self, ParseError>
     {
-        let (operations, result) = input.split_once("->").unwrap(); 
+        const EXPECTED: &str = "expected a gate like 'x00 AND y00 -> z00'";
+
+        let Some((operations, result)) = input.split_once("->") else
+        {
+            return Err(ParseError::new(line_num, 1, EXPECTED));
+        };
         let result = String::from(result.trim());
 
-        let mut operations = operations.trim().split(" ");
+        let operations: Vec<&str> = operations.split_whitespace().collect();
+        if operations.len() != 3 || result.is_empty()
+        {
+            return Err(ParseError::new(line_num, 1, EXPECTED));
+        }
 
-        let left = String::from(operations.next().unwrap().trim());
-        let operator = Operator::from(operations.next().unwrap().trim());
-        let right = String::from(operations.next().unwrap().trim());
+        let left = String::from(operations[0]);
+        let operator = Operator::from(line_num, input, operations[1])?;
+        let right = String::from(operations[2]);
 
-        Self
+        return Ok(Self
         {
-            left: left,
-            right: right,
-            operator: operator,
-            result: result
-        }
+            left,
+            right,
+            operator,
+            result
+        });
     }
 
     pub fn attempt_solve(&self, known_values: &HashMap<String, bool>) -> Option<bool>
@@ -95,41 +105,52 @@ impl Equation
 
 // Machine
 #[derive(Clone)]
-struct Machine
+pub struct Machine
 {
-    known_values: HashMap<String, bool>,
-    equations: Vec<Equation>
+    pub known_values: HashMap<String, bool>,
+    pub equations: Vec<Equation>
 }
 
 impl Machine
 {
-    fn from(input: &str) -> Self
+    pub fn from(input: &str) -> Result<Self, ParseError>
     {
-        let (init_bits_str, gates_str) = input.split_once("\n\n").unwrap();
+        let sections = split_sections(input);
+        if sections.len() != 2
+        {
+            return Err(ParseError::new(1, 1, "expected the wire values and the gates separated by a blank line"));
+        }
+
+        let (init_bits_line, init_bits_str) = sections[0];
+        let (gates_line, gates_str) = sections[1];
 
         // Find initial values.
         let mut known_values = HashMap::new();
-        for line in init_bits_str.lines().filter(|l| l.len() > 0)
+        for (line_num, line) in numbered_lines(init_bits_str).filter(|(_, l)| !l.is_empty())
         {
-            let (symbol, num) = line.split_once(":").unwrap();
+            let line_num = init_bits_line + line_num - 1;
+            let Some((symbol, num)) = line.split_once(":") else
+            {
+                return Err(ParseError::new(line_num, 1, "expected a wire value like 'x00: 1'"));
+            };
 
             let symbol = String::from(symbol);
-            let num = num.trim().parse::<i32>().unwrap() != 0;
+            let num = parse_token::<i32>(line_num, line, num.trim())? != 0;
 
             known_values.insert(symbol, num);
         }
 
         // Find equations
-        let equations: Vec<Equation> = gates_str.lines()
-                                    .filter(|l| l.len() > 0)
-                                    .map(|l| Equation::from(l))
-                                    .collect();
+        let equations: Vec<Equation> = numbered_lines(gates_str)
+                                    .filter(|(_, l)| !l.is_empty())
+                                    .map(|(line_num, l)| Equation::from(gates_line + line_num - 1, l))
+                                    .collect::<Result<_, _>>()?;
         
-        Self
+        return Ok(Self
         {
-            known_values: known_values,
-            equations: equations
-        }
+            known_values,
+            equations
+        });
     }
 
     fn set_init_registers(&mut self, x: i64, y: i64)
@@ -209,38 +230,36 @@ impl Machine
     }
 }
 
-pub fn compute_answer(input: &str) -> i64
+pub fn compute_answer(input: &str, num_bits_to_search: usize) -> Result<i64, ParseError>
+{
+    let machine = Machine::from(input)?;
+
+    return Ok(fixed_machine_answer(&machine, num_bits_to_search));
+}
+
+pub fn fixed_machine_answer(machine: &Machine, num_bits_to_search: usize) -> i64
 {
-    let mut machine = Machine::from(input);
+    let mut machine = machine.clone();
 
-    fix_machine(&mut machine);
+    fix_machine(&mut machine, num_bits_to_search);
 
     return 0;
 }
 
-fn fix_machine(machine: &mut Machine)
+fn fix_machine(machine: &mut Machine, num_bits_to_search: usize)
 {
-    const NUM_BITS_TO_SEARCH : usize = 45;
-
-    let mut last_left_off_idx : Vec<usize> = vec![0; NUM_BITS_TO_SEARCH];
+    let mut last_left_off_idx : Vec<usize> = vec![0; num_bits_to_search];
 
     let all_swaps = generate_swaps(machine.equations.len());
     let mut cont = true;
 
     while cont
     {
-        println!("");
-        println!("NEW RUN==========");
-
         let mut machine = machine.clone(); // Start a fresh machine.
         cont = false; // Assume this will work. Chat is this the run?
 
-        let mut swaps_that_fixed: Vec<(usize, usize)> = Vec::new();
-
-        for bit in 7..=NUM_BITS_TO_SEARCH
+        for bit in 7..=num_bits_to_search
         {
-            println!("");
-            println!("Machine test {}", bit);
             if test_machine(bit, &mut machine)
             {
                 // Machine works for this so it's fine.
@@ -250,13 +269,10 @@ fn fix_machine(machine: &mut Machine)
             let mut swap_that_fixed = None;
             let start_idx = last_left_off_idx[bit-1];
 
-            println!("   Not working, attempting swaps from {start_idx}");
-
-            for i in start_idx..all_swaps.len()
+            for (i, swap_ids) in all_swaps.iter().copied().enumerate().skip(start_idx)
             {
                 last_left_off_idx[bit-1] = i+1;
 
-                let swap_ids = all_swaps[i];
                 let banned_swap_id = (bit, swap_ids.0, swap_ids.1);
 
                 machine.swap_wires(swap_ids.0, swap_ids.1);
@@ -273,37 +289,23 @@ fn fix_machine(machine: &mut Machine)
                 }
             }
             
-            if let Some(swap_that_fixed) = swap_that_fixed
+            if swap_that_fixed.is_some()
             {
-                swaps_that_fixed.push(swap_that_fixed);
-
-                let wire1_name = &machine.equations[swap_that_fixed.0].result;
-                let wire2_name = &machine.equations[swap_that_fixed.1].result;
-
-                println!("    Swap ({}, {}) fixed it.", wire1_name, wire2_name);
-
-                for i in 0..(bit-1)
+                for left_off_idx in last_left_off_idx.iter_mut().take(bit-1)
                 {
-                    if last_left_off_idx[i] > 0
+                    if *left_off_idx > 0
                     {
-                        last_left_off_idx[i] -= 1;
+                        *left_off_idx -= 1;
                     }
                 }
             }
             else
             {
                 last_left_off_idx[bit-1] = 0;
-                println!("    Couldn't fix machine on bit {bit}. Trying again.");
                 cont = true;
                 break;
             }
         }
-
-        if cont == false
-        {
-            swaps_that_fixed.sort();
-            println!("Swaps {:?}", swaps_that_fixed);
-        }
     }
 }
 
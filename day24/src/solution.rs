@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day24Params
+{
+    // Output bits part 2 checks while looking for swapped wires.
+    #[serde(rename = "num_bits_to_search")]
+    pub m_num_bits_to_search: usize,
+}
+
+impl Default for Day24Params
+{
+    fn default() -> Self
+    {
+        return Self
+        {
+            m_num_bits_to_search: 45,
+        };
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24
+{
+    type Params = Day24Params;
+    type Parsed1 = (HashMap<String, bool>, Vec<part1::Equation>);
+    type Parsed2 = part2::Machine;
+
+    const DAY: u32 = 24;
+
+    fn parse_part1(input: &String, _params: &Self::Params) -> Result<Self::Parsed1, ParseError>
+    {
+        return part1::parse_wires(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &Self::Params) -> Answer
+    {
+        let (known_values, equations) = parsed;
+        return Answer::from(part1::z_output(known_values, equations));
+    }
+
+    fn parse_part2(input: &String, _params: &Self::Params) -> Result<Self::Parsed2, ParseError>
+    {
+        return part2::Machine::from(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, params: &Self::Params) -> Answer
+    {
+        return Answer::from(part2::fixed_machine_answer(parsed, params.m_num_bits_to_search));
+    }
+}
\ No newline at end of file
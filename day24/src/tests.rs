@@ -2,6 +2,7 @@
 mod tests 
 {
     use crate::part1;
+    use aoc_core::parse_error::ParseError;
     use crate::part2;
 
     const TEST_STR: &str = r"x00: 1
@@ -56,7 +57,14 @@ tnw OR pbm -> gnj";
     fn part_1_test() 
     {
         let result = part1::compute_answer(TEST_STR);
-        assert_eq!(result, 2024);
+        assert_eq!(result, Ok(2024));
+    }
+
+    #[test]
+    fn bad_operator_test() 
+    {
+        let result = part1::compute_answer("x00: 1\ny00: 0\n\nx00 NAND y00 -> z00");
+        assert_eq!(result, Err(ParseError::new(4, 5, "unknown operator 'NAND'")));
     }
 
     // #[test]
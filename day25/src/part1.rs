@@ -1,18 +1,36 @@
 use std::thread::LocalKey;
 
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 
-pub fn compute_answer(input: &str) -> i32
+pub fn compute_answer(input: &str) -> Result<i32, ParseError>
+{
+    let (lock_grids, key_grids) = parse_schematics(input)?;
+
+    return Ok(count_fitting_pairs(&lock_grids, &key_grids));
+}
+
+// Locks first, then keys.
+pub fn parse_schematics(input: &str) -> Result<(Vec<CharGrid>, Vec<CharGrid>), ParseError>
 {
     let mut lock_grids: Vec<CharGrid> = Vec::new();
     let mut key_grids: Vec<CharGrid> = Vec::new();
+    let mut first_dims: Option<(i32, i32)> = None;
 
-    for char_grid in input.split("\n\n")
+    for (first_line, char_grid) in split_sections(input)
     {
-        let char_grid = CharGrid::from(char_grid);
+        check_schematic(first_line, char_grid)?;
+
+        let char_grid = CharGrid::parse(char_grid).map_err(|err| err.offset_lines(first_line))?;
+        let dims = (char_grid.m_width, char_grid.m_height);
+        if *first_dims.get_or_insert(dims) != dims
+        {
+            return Err(ParseError::new(first_line, 1, "schematic is a different size to the first one"));
+        }
+
         if char_grid.at(0, 0).unwrap() == '#'
         {
             lock_grids.push(char_grid);
@@ -23,6 +41,11 @@ pub fn compute_answer(input: &str) -> i32
         }
     }
 
+    return Ok((lock_grids, key_grids));
+}
+
+pub fn count_fitting_pairs(lock_grids: &Vec<CharGrid>, key_grids: &Vec<CharGrid>) -> i32
+{
     let mut total_matches = 0;
     for lock_grid in lock_grids.iter()
     {
@@ -40,6 +63,23 @@ pub fn compute_answer(input: &str) -> i32
     return total_matches;
 }
 
+// Schematics are made of '#' and '.', the grid checks they are rectangles.
+fn check_schematic(first_line: usize, schematic: &str) -> Result<(), ParseError>
+{
+    for (line_num, line) in numbered_lines(schematic)
+    {
+        let line_num = first_line + line_num - 1;
+        let line = line.trim_end();
+
+        if let Some((idx, c)) = line.chars().enumerate().find(|(_, c)| *c != '#' && *c != '.')
+        {
+            return Err(ParseError::new(line_num, idx + 1, format!("unexpected character '{c}' in schematic")));
+        }
+    }
+
+    return Ok(());
+}
+
 
 fn key_fits_in_lock(lock_grid: &CharGrid, key_grid: &CharGrid) -> bool
 {
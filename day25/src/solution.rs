@@ -0,0 +1,39 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+use aoc_core::char_grid::CharGrid;
+
+use crate::{part1, part2};
+
+pub struct Day25;
+
+impl Solution for Day25
+{
+    type Params = NoParams;
+    type Parsed1 = (Vec<CharGrid>, Vec<CharGrid>);
+    type Parsed2 = ();
+
+    const DAY: u32 = 25;
+    const PART_KINDS: [PartKind; 2] = [PartKind::Solver, PartKind::Missing];
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return part1::parse_schematics(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        let (lock_grids, key_grids) = parsed;
+        return Answer::from(part1::count_fitting_pairs(lock_grids, key_grids));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return Ok(());
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part2::compute_answer(""));
+    }
+}
\ No newline at end of file
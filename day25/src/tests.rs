@@ -3,6 +3,7 @@ mod tests
 {
     use crate::part1;
     use crate::part2;
+    use aoc_core::parse_error::ParseError;
 
     const TEST_STR: &str = r"#####
 .####
@@ -48,7 +49,14 @@ mod tests
     fn part_1_test() 
     {
         let result = part1::compute_answer(TEST_STR);
-        assert_eq!(result, 3);
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn bad_schematic_test() 
+    {
+        let result = part1::compute_answer("#####\n.#x##\n.....");
+        assert_eq!(result, Err(ParseError::new(2, 3, "unexpected character 'x' in schematic")));
     }
 
     // #[test]
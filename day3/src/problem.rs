@@ -2,13 +2,19 @@ use regex::Regex;
 
 pub fn resolve_mults(input : String) -> i32
 {
-    let find_mult_commands = Regex::new(r"mul\(\d+,\d+\)").unwrap();
-    let matches : Vec<&str> = find_mult_commands.find_iter(&input).map(|mat| mat.as_str()).collect();
+    return sum_mults(&find_mults(&input));
+}
+
+pub fn find_mults(input : &str) -> Vec<(i32, i32)>
+{
+    let find_mult_commands = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
+    let matches : Vec<&str> = find_mult_commands.find_iter(input).map(|mat| mat.as_str()).collect();
 
-    let mut total_sum : i32 = 0; 
+    let find_numbers = Regex::new(r"(\d+)").unwrap();
+
+    let mut mults : Vec<(i32, i32)> = Vec::new();
     for re_match in matches.iter()
     {
-        let find_numbers = Regex::new(r"(\d+)").unwrap();
         let captures = find_numbers.captures_iter(re_match);
 
         let numbers: Vec<i32> = captures
@@ -16,16 +22,27 @@ pub fn resolve_mults(input : String) -> i32
             .map(|m| m.as_str().parse::<i32>().unwrap())
             .collect();
 
-        total_sum += numbers[0] * numbers[1];
+        mults.push((numbers[0], numbers[1]));
     }   
 
-    return total_sum;
+    return mults;
+}
+
+pub fn sum_mults(mults : &Vec<(i32, i32)>) -> i32
+{
+    return mults.iter().map(|(a, b)| a * b).sum();
 }
 
 pub fn resolve_mults_do_dont(input : String) -> i32
+{
+    return sum_mults(&find_enabled_mults(&input));
+}
+
+// Mults that aren't switched off by a don't().
+pub fn find_enabled_mults(input : &str) -> Vec<(i32, i32)>
 {
     let remove_donts  = Regex::new(r"(?s)don't\(\)(.)*?do\(\)").unwrap();
-    let trimmed_string = remove_donts.replace_all(&input, "");
+    let trimmed_string = remove_donts.replace_all(input, "");
 
-    return resolve_mults(trimmed_string.to_string());
+    return find_mults(&trimmed_string);
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem;
+
+pub struct Day3;
+
+impl Solution for Day3
+{
+    type Params = NoParams;
+    type Parsed1 = Vec<(i32, i32)>;
+    type Parsed2 = Vec<(i32, i32)>;
+
+    const DAY: u32 = 3;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return Ok(problem::find_mults(input));
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::sum_mults(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return Ok(problem::find_enabled_mults(input));
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::sum_mults(parsed));
+    }
+}
\ No newline at end of file
@@ -1,34 +1,28 @@
-use strum::{EnumIter, IntoEnumIterator};
+use glam::IVec2;
+
+use aoc_core::char_grid::CharGrid;
+use aoc_core::direction::Direction;
 
 const XMAS_STRING : &str = "XMAS";
-const MAS_STRING : &str = "MAS";
 
-struct CrossWord
-{
-    m_width : i32,
-    m_height : i32,
-    m_data : Vec<char>
-}
+// Two MASes crossing, '.' can be anything. Its four rotations are every way the MASes can read.
+const MAS_CROSS_STR : &str = "M.S
+.A.
+M.S";
 
-#[derive(EnumIter)]
-enum SearchDir
+pub struct CrossWord
 {
-    N,
-    NE,
-    E,
-    SE,
-    S,
-    SW,
-    W,
-    NW
+    pub m_width : i32,
+    pub m_height : i32,
+    pub m_data : Vec<char>
 }
 
 impl CrossWord
 {
-    fn from(input : &String) -> Self 
+    pub fn from(input : &String) -> Self 
     {
-        let width = count_chars_before_newline(&input) as i32;
-        let height = count_lines(&input) as i32;
+        let width = count_chars_before_newline(input) as i32;
+        let height = count_lines(input) as i32;
 
         let san_string = strip_newlines(input.as_str());
         let chars: Vec<char> = san_string.chars().collect();
@@ -64,10 +58,10 @@ impl CrossWord
         return self.m_data[index as usize];
     }
 
-    fn is_word(&self, x: i32, y: i32, dir: SearchDir, word: &str) -> bool
+    fn is_word(&self, x: i32, y: i32, dir: Direction, word: &str) -> bool
     {
         let mut search_i: usize = 0;
-        let dir = dir_to_tuple(dir);
+        let dir = dir.to_vec();
         let mut point: (i32, i32) = (x, y);
 
         while search_i < word.len()
@@ -80,7 +74,7 @@ impl CrossWord
                 return false;
             }
 
-            point = (point.0 + dir.0, point.1 + dir.1);
+            point = (point.0 + dir.x, point.1 + dir.y);
 
             search_i += 1;
         }
@@ -88,33 +82,32 @@ impl CrossWord
         return true;
     }
 
-    fn is_cross(&self, x: i32, y: i32, word: &str) -> bool
+    pub fn to_grid(&self) -> CharGrid
     {
-        // Check \ diag
-        if self.is_word(x, y, SearchDir::SE, word) || self.is_word(x+2, y+2, SearchDir::NW, word)
-        {
-            // Check / diag
-            if self.is_word(x+2, y, SearchDir::SW, word) || self.is_word(x, y+2, SearchDir::NE, word)
-            {
-                return true;
-            }
-        }
-
-        return false;
+        return CharGrid::from_fn(self.m_width, self.m_height, |pos| self.at(pos.x, pos.y));
     }
 }
 
+fn matches_pattern(window: &CharGrid, pattern: &CharGrid) -> bool
+{
+    return pattern.cells().all(|(pos, c)| *c == '.' || window[pos] == *c);
+}
+
 // Problem
 pub fn count_xmas(input : &String) -> i32
 {
-    let cw: CrossWord = CrossWord::from(input);
+    return count_xmas_in_crossword(&CrossWord::from(input));
+}
+
+pub fn count_xmas_in_crossword(cw: &CrossWord) -> i32
+{
     let mut total_matches = 0;
 
     for x in 0..cw.m_width
     {
         for y in 0..cw.m_height
         {
-            for dir in SearchDir::iter()
+            for dir in Direction::iter_8()
             {
                 if cw.is_word(x, y, dir, XMAS_STRING)
                 {
@@ -129,14 +122,27 @@ pub fn count_xmas(input : &String) -> i32
 
 pub fn count_mas_crosses(input : &String) -> i32
 {
-    let cw: CrossWord = CrossWord::from(input);
+    return count_mas_crosses_in_crossword(&CrossWord::from(input));
+}
+
+pub fn count_mas_crosses_in_crossword(cw: &CrossWord) -> i32
+{
+    let grid = cw.to_grid();
+
+    let mut patterns = vec![CharGrid::from(MAS_CROSS_STR)];
+    for _ in 0..3
+    {
+        patterns.push(patterns.last().unwrap().rotate_right());
+    }
+
     let mut total_matches = 0;
 
-    for x in 0..cw.m_width
+    for x in 0..cw.m_width - 2
     {
-        for y in 0..cw.m_height
+        for y in 0..cw.m_height - 2
         {
-            if cw.is_cross(x, y, MAS_STRING)
+            let window = grid.crop(IVec2::new(x, y), IVec2::splat(3));
+            if patterns.iter().any(|pattern| matches_pattern(&window, pattern))
             {
                 total_matches += 1;
             }
@@ -151,9 +157,7 @@ pub fn count_mas_crosses(input : &String) -> i32
 fn count_chars_before_newline(text: &String) -> usize
 {
     let mut count = 0;
-    let mut char_it = text.chars().peekable();
-
-    while let Some(c) = char_it.next()
+    for c in text.chars()
     {
         count += 1;
         if c == '\r' || c == '\n'
@@ -172,44 +176,5 @@ fn count_lines(text: &String) -> usize
 
 fn strip_newlines(s: &str) -> String
 {
-    return s.replace('\n', "").replace('\r', "");
-}
-
-fn dir_to_tuple(dir: SearchDir) -> (i32, i32)
-{
-    match dir
-    {
-        SearchDir::N =>
-        {
-            return (0, -1);
-        }
-        SearchDir::NE =>
-        {
-            return (1, -1);
-        }
-        SearchDir::E =>
-        {
-            return (1, 0);
-        }
-        SearchDir::SE =>
-        {
-            return (1, 1);
-        }
-        SearchDir::S =>
-        {
-            return (0, 1);
-        }
-        SearchDir::SW =>
-        {
-            return (-1, 1);
-        }
-        SearchDir::W =>
-        {
-            return (-1, 0);
-        }
-        SearchDir::NW =>
-        {
-            return (-1, -1);
-        }
-    };
+    return s.replace(['\n', '\r'], "");
 }
\ No newline at end of file
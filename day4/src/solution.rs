@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem::{self, CrossWord};
+
+pub struct Day4;
+
+impl Solution for Day4
+{
+    type Params = NoParams;
+    type Parsed1 = CrossWord;
+    type Parsed2 = CrossWord;
+
+    const DAY: u32 = 4;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return Ok(CrossWord::from(input));
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::count_xmas_in_crossword(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return Ok(CrossWord::from(input));
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::count_mas_crosses_in_crossword(parsed));
+    }
+}
\ No newline at end of file
@@ -1,38 +1,35 @@
 use std::collections::HashMap;
-use regex::Regex;
+
+use aoc_core::parse_error::*;
 
 const MAX_ITER_ATTEMPTS : usize = 100000;
 
 // Update
 #[derive(Debug, Clone)]
-struct Update
+pub struct Update
 {
-    m_pages : Vec<i32>,
-    m_page_to_index : HashMap<i32, usize>
+    pub m_pages : Vec<i32>,
+    pub m_page_to_index : HashMap<i32, usize>
 }
 
 impl Update
 {
-    fn from(line: &str) -> Result<Update,()>
+    fn from(line_num: usize, line: &str) -> Result<Update, ParseError>
     {
-        let page_numbers = line.split(',').map(|l| l.parse::<i32>()).collect();
-        let page_numbers : Vec<i32> = match page_numbers
+        let page_numbers : Vec<i32> = line.split(',')
+                                        .map(|page| parse_token(line_num, line, page))
+                                        .collect::<Result<_, _>>()?;
+
+        if page_numbers.len().is_multiple_of(2)
         {
-            Ok(numbers) =>
-            {
-                numbers
-            }
-            Err(_) =>
-            {
-                return Err(());
-            }
-        };
+            return Err(ParseError::new(line_num, 1, "update has an even number of pages, so there is no middle page"));
+        }
 
         let mut page_to_index: HashMap<i32, usize> = HashMap::new();
 
-        for i in 0..page_numbers.len()
+        for (i, page) in page_numbers.iter().enumerate()
         {
-            page_to_index.insert(page_numbers[i], i);
+            page_to_index.insert(*page, i);
         }
 
         let result : Update = Update
@@ -53,8 +50,8 @@ impl Update
 
     fn swap_pages(&mut self, page1 : i32, page2: i32)
     {
-        let page1_idx = self.m_page_to_index.get(&page1).expect("Page not in rule?!").clone();
-        let page2_idx = self.m_page_to_index.get(&page2).expect("Page not in rule?!").clone();
+        let page1_idx = *self.m_page_to_index.get(&page1).expect("Page not in rule?!");
+        let page2_idx = *self.m_page_to_index.get(&page2).expect("Page not in rule?!");
 
         self.m_pages.swap(page1_idx, page2_idx);
 
@@ -93,42 +90,28 @@ impl Update
 
 // Rule
 #[derive(Debug, Clone)]
-struct Rule
+pub struct Rule
 {
-    m_before_page : i32,
-    m_page : i32
+    pub m_before_page : i32,
+    pub m_page : i32
 }
 
 impl Rule
 {
-    fn from(line: &str) -> Result<Rule,()>
+    fn from(line_num: usize, line: &str) -> Result<Rule, ParseError>
     {
-        let find_numbers = Regex::new(r"(\d+)\|(\d+)").unwrap();
-        let mut captures_iter = find_numbers.captures_iter(line);
-
-        if let Some(captures) = captures_iter.next()
+        let Some((first_number, second_number)) = line.split_once('|') else
         {
-            // Access the first and second capture groups
-            let first_number = captures.get(1).map(|m| m.as_str()).ok_or(())?;
-            let second_number = captures.get(2).map(|m| m.as_str()).ok_or(())?;
-
-            let first_number = first_number.parse::<i32>().map_err(|_| ())?;
-            let second_number = second_number.parse::<i32>().map_err(|_| ())?;
-
-            let result : Rule = Rule
-            {
-                m_before_page: first_number,
-                m_page: second_number
-            };
+            return Err(ParseError::new(line_num, 1, "expected a rule like 47|53"));
+        };
 
-            return Ok(result);
-        }
-        else
+        let result : Rule = Rule
         {
-            println!("No match found");
-        }
+            m_before_page: parse_token(line_num, line, first_number)?,
+            m_page: parse_token(line_num, line, second_number)?
+        };
 
-        return Err(());
+        return Ok(result);
     }
 
     fn breaks_rule(&self, update: &Update) -> bool
@@ -152,14 +135,18 @@ impl Rule
 
 
 // Problem
-pub fn count_middle_pages_of_updates(input: &String) -> i32
+pub fn count_middle_pages_of_updates(input: &String) -> Result<i32, ParseError>
 {
-    let (rules, updates) = parse_input(input);
+    let (rules, updates) = parse_input(input)?;
+    return Ok(sum_middle_pages_of_updates(&rules, &updates));
+}
 
+pub fn sum_middle_pages_of_updates(rules: &Vec<Rule>, updates: &Vec<Update>) -> i32
+{
     let mut middle_page_count = 0;
     for update in updates.iter()
     {
-        if rules.iter().any(|rule| rule.breaks_rule(&update)) == false
+        if !rules.iter().any(|rule| rule.breaks_rule(update))
         {
             middle_page_count += update.get_middle_page_num();
         }
@@ -169,10 +156,14 @@ pub fn count_middle_pages_of_updates(input: &String) -> i32
 }
 
 
-pub fn count_middle_pages_of_fixuped_updates(input: &String) -> i32
+pub fn count_middle_pages_of_fixuped_updates(input: &String) -> Result<i32, ParseError>
 {
-    let (rules, updates) = parse_input(input);
+    let (rules, updates) = parse_input(input)?;
+    return Ok(sum_middle_pages_of_fixuped_updates(&rules, &updates));
+}
 
+pub fn sum_middle_pages_of_fixuped_updates(rules: &Vec<Rule>, updates: &Vec<Update>) -> i32
+{
     // Filter out working updates.
     let mut broken_updates : Vec<Update> = updates.iter()
                                     .filter(|u| rules.iter().any(|rule| rule.breaks_rule(u)))
@@ -182,7 +173,7 @@ pub fn count_middle_pages_of_fixuped_updates(input: &String) -> i32
     // Fix broken updates.
     for update in broken_updates.iter_mut()
     {
-        update.fix(&rules);
+        update.fix(rules);
     }
 
     let mut middle_page_count = 0;
@@ -196,31 +187,27 @@ pub fn count_middle_pages_of_fixuped_updates(input: &String) -> i32
 
 
 // Parse
-fn parse_input(input: &String) -> (Vec<Rule>, Vec<Update>)
+pub fn parse_input(input: &String) -> Result<(Vec<Rule>, Vec<Update>), ParseError>
 {
     let mut rules : Vec<Rule> = Vec::new();
     let mut updates : Vec<Update> = Vec::new();
 
-    for line in input.lines()
+    for (line_num, line) in numbered_lines(input)
     {
-        if line.len() == 0
+        if line.is_empty()
         {
             continue;
         }
 
-        if let Ok(update) = Update::from(line)
-        {
-            updates.push(update);
-        }
-        else if let Ok(rule) = Rule::from(line)
+        if line.contains('|')
         {
-            rules.push(rule);
+            rules.push(Rule::from(line_num, line)?);
         }
         else
         {
-            panic!("Non-empty line cannot be parsed");     
+            updates.push(Update::from(line_num, line)?);
         }
     }
 
-    return (rules, updates);
+    return Ok((rules, updates));
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem::{self, Rule, Update};
+
+pub struct Day5;
+
+impl Solution for Day5
+{
+    type Params = NoParams;
+    type Parsed1 = (Vec<Rule>, Vec<Update>);
+    type Parsed2 = (Vec<Rule>, Vec<Update>);
+
+    const DAY: u32 = 5;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return problem::parse_input(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        let (rules, updates) = parsed;
+        return Answer::from(problem::sum_middle_pages_of_updates(rules, updates));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return problem::parse_input(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        let (rules, updates) = parsed;
+        return Answer::from(problem::sum_middle_pages_of_fixuped_updates(rules, updates));
+    }
+}
\ No newline at end of file
@@ -2,6 +2,7 @@
 mod tests 
 {
     use crate::problem;
+    use aoc_core::parse_error::ParseError;
 
     const TEST_STR: &str = r"47|53
 97|13
@@ -36,13 +37,20 @@ mod tests
     fn part_1_test() 
     {
         let result = problem::count_middle_pages_of_updates(&String::from(TEST_STR));
-        assert_eq!(result, 143);
+        assert_eq!(result, Ok(143));
     }
 
     #[test]
     fn part_2_test() 
     {
         let result = problem::count_middle_pages_of_fixuped_updates(&String::from(TEST_STR));
-        assert_eq!(result, 123);
+        assert_eq!(result, Ok(123));
+    }
+
+    #[test]
+    fn bad_page_number_test() 
+    {
+        let result = problem::count_middle_pages_of_updates(&String::from("47|53\n\n75,x7,61"));
+        assert_eq!(result, Err(ParseError::new(3, 4, "expected a number, found 'x7'")));
     }
 }
\ No newline at end of file
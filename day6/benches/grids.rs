@@ -0,0 +1,22 @@
+use std::fs;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day6::problem::problem;
+
+// The obstruction search on the Lab tile grid against the padded ByteGrid version.
+pub fn criterion_benchmark(c: &mut Criterion)
+{
+    // Read once so the disk isn't part of what we measure.
+    let input = fs::read_to_string("./input.txt").expect("Should have been able to read the file");
+    let (maze, guard) = problem::parse_string(&input).expect("Should have been able to parse the input");
+
+    let mut group = c.benchmark_group("Obstructions");
+    group.sample_size(10);
+    group.bench_function("Lab", |b| b.iter(|| problem::count_infinite_obstructions(black_box(&maze), black_box(&guard))));
+    group.bench_function("ByteGrid", |b| b.iter(|| problem::count_infinite_obstructions_bytes(black_box(&maze), black_box(&guard))));
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
\ No newline at end of file
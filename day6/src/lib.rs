@@ -0,0 +1,3 @@
+mod tests;
+pub mod problem;
+pub mod solution;
\ No newline at end of file
@@ -1,11 +1,44 @@
 use std::collections::HashSet;
-use super::direction::Direction;
-use super::char_grid::CharGrid;
+use glam::IVec2;
+use aoc_core::direction::Direction;
+use aoc_core::grid::Grid;
+use aoc_core::tile_grid::Tile;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabTile
+{
+    Floor,
+    Obstruction,
+}
+
+impl Tile for LabTile
+{
+    fn from_char(c: char) -> Option<Self>
+    {
+        match c
+        {
+            '.' => { return Some(LabTile::Floor); }
+            '#' => { return Some(LabTile::Obstruction); }
+            _ => { return None; }
+        }
+    }
+
+    fn to_char(&self) -> char
+    {
+        match self
+        {
+            LabTile::Floor => { return '.'; }
+            LabTile::Obstruction => { return '#'; }
+        }
+    }
+}
+
+pub type Lab = Grid<LabTile>;
 
 #[derive(Debug, Clone)]
 pub struct Guard
 {
-    pub m_pos: (i32, i32),
+    pub m_pos: IVec2,
     pub m_facing: Direction
 }
 
@@ -15,68 +48,66 @@ impl Guard
     {
         Self 
         {
-            m_pos: (x, y),
+            m_pos: IVec2::new(x, y),
             m_facing: facing
         }
     }
 
-    fn walk_step(&mut self, maze: &CharGrid)
+    fn walk_step(&mut self, maze: &Lab)
     {
         // Move one forward.
         let next_pos = self.m_facing.add_to(self.m_pos);
-        let next_char = maze.at_vec(next_pos);
 
         // Next space is free
-        if next_char.is_none() || next_char.unwrap() == '.'
-        {
-            // March step
-            self.m_pos = next_pos;
-        }
-        else
+        match maze.at_vec(next_pos)
         {
-            // Hit wall
-            let next_char = next_char.unwrap();
-            assert!(next_char == '#', "Unexpected character found in maze: |{next_char}| Wanted wall. ");
-
-            // Turn right.
-            self.m_facing = self.m_facing.rot_right(); 
+            None | Some(LabTile::Floor) =>
+            {
+                // March step
+                self.m_pos = next_pos;
+            }
+            Some(LabTile::Obstruction) =>
+            {
+                // Turn right.
+                self.m_facing = self.m_facing.rot_right(); 
+            }
         }
     }
 }
 
 // Walk out of maze then get number spaces visited it took to do so.
-pub fn walk_until_out_of_maze_visited(guard: &Guard, maze: &CharGrid) -> i32
+pub fn walk_until_out_of_maze_visited(guard: &Guard, maze: &Lab) -> i32
 {
     let mut guard= guard.clone();
-    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+    let mut visited: HashSet<IVec2> = HashSet::new();
 
     while maze.inside_grid_vec(guard.m_pos)
     {
         visited.insert(guard.m_pos);
-        guard.walk_step(&maze);
+        guard.walk_step(maze);
     }
 
     return visited.len() as i32;
 }
 
 // Check if this guard is in an infinite loop
-pub fn is_in_infinite_loop(guard: &Guard, maze: &CharGrid) -> bool
+pub fn is_in_infinite_loop(guard: &Guard, maze: &Lab) -> bool
 {
     let mut guard= guard.clone();
-    let mut visited_dir: HashSet<(i32, i32, Direction)> = HashSet::new();
+    let mut visited_dir: HashSet<(IVec2, Direction)> = HashSet::new();
 
     while maze.inside_grid_vec(guard.m_pos)
     {
-        let guard_state = (guard.m_pos.0, guard.m_pos.1, guard.m_facing.clone());
+        let guard_state = (guard.m_pos, guard.m_facing);
         let new_state = visited_dir.insert(guard_state);
 
         // We have been here before, facing the same direction. Stuck in a loop.
-        if new_state == false
+        if !new_state
         {
             return true;
         }
 
-        guard.walk_step(&maze);
+        guard.walk_step(maze);
     }
 
     // We escaped the maze, not stuck.
@@ -1,4 +1,2 @@
-mod direction;
-mod char_grid;
-mod guard;
+pub mod guard;
 pub mod problem;
\ No newline at end of file
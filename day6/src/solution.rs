@@ -0,0 +1,39 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem::problem;
+use crate::problem::guard::{Guard, Lab};
+
+pub struct Day6;
+
+impl Solution for Day6
+{
+    type Params = NoParams;
+    type Parsed1 = (Lab, Guard);
+    type Parsed2 = (Lab, Guard);
+
+    const DAY: u32 = 6;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return problem::parse_string(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        let (maze, guard) = parsed;
+        return Answer::from(problem::count_guard_positions(maze, guard));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return problem::parse_string(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        let (maze, guard) = parsed;
+        return Answer::from(problem::count_infinite_obstructions_bytes(maze, guard));
+    }
+}
\ No newline at end of file
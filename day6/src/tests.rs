@@ -18,13 +18,29 @@ mod tests
     fn part_1_test() 
     {
         let result = problem::count_number_of_guard_positions(&String::from(TEST_STR));
-        assert_eq!(result, 41);
+        assert_eq!(result, Ok(41));
     }
 
     #[test]
     fn part_2_test() 
     {
          let result = problem::count_number_of_infinite_obstructions(&String::from(TEST_STR));
-         assert_eq!(result, 6);
+         assert_eq!(result, Ok(6));
+
+         let (maze, guard) = problem::parse_string(&String::from(TEST_STR)).unwrap();
+         assert_eq!(problem::count_infinite_obstructions_bytes(&maze, &guard), 6);
+    }
+
+    #[test]
+    fn line_endings_test() 
+    {
+        let crlf_input = TEST_STR.replace('\n', "\r\n") + "\r\n\r\n";
+        let result = problem::count_number_of_guard_positions(&crlf_input);
+        assert_eq!(result, Ok(41));
+
+        // Third row is one short.
+        let ragged_input = TEST_STR.replacen("..........", ".........", 1);
+        let result = problem::count_number_of_guard_positions(&ragged_input);
+        assert_eq!(result.map_err(|err| (err.m_line, err.m_column)), Err((3, 10)));
     }
 }
\ No newline at end of file
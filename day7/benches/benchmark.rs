@@ -1,8 +1,18 @@
-use criterion::{criterion_group, criterion_main, Criterion};
+use std::fs;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day7::problem;
 
 pub fn criterion_benchmark(c: &mut Criterion)
 {
-    c.bench_function("Part 1&2", |b| b.iter(|| day7::get_results_program()));
+    // Read once so the disk isn't part of what we measure.
+    let input = fs::read_to_string("./input.txt").expect("Should have been able to read the file");
+    let tests = problem::parse_tests(&input).expect("Should have been able to parse the input");
+
+    c.bench_function("Parse", |b| b.iter(|| problem::parse_tests(black_box(&input))));
+    c.bench_function("Part 1", |b| b.iter(|| problem::sum_valid_tests_mult_or_add(black_box(&tests))));
+    c.bench_function("Part 2", |b| b.iter(|| problem::sum_valid_tests_mult_or_add_or_concat(black_box(&tests))));
 }
 
 criterion_group!(benches, criterion_benchmark);
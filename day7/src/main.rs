@@ -1,10 +1,8 @@
-mod tests;
-mod problem;
+use aoc_core::solution::run_main;
+
+use day7::solution::Day7;
 
 fn main()
 {
-    let (part1, part2) = day7::get_results_program();
-
-    println!("Part 1: {}", part1);
-    println!("Part 2: {}", part2);
-}
+    run_main::<Day7>();
+}
\ No newline at end of file
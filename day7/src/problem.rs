@@ -1,28 +1,37 @@
+use aoc_core::parse_error::*;
 
-struct TestLine
+pub struct TestLine
 {
-    m_expected_value: i64,
-    m_operands: Vec<i64>
+    pub m_expected_value: i64,
+    pub m_operands: Vec<i64>
 }
 
 
 impl TestLine
 {
-    fn from(line: &str) -> Self
+    fn from(line_num: usize, line: &str) -> Result<Self, ParseError>
     {
-        let line_split : Vec<&str> = line.split(':').collect();
-        assert!(line_split.len() == 2);
-        let expected_val: i64 = line_split[0].parse().expect("Parse error.");
-        let operands = line_split[1].split(' ')
-                                                .filter(|n| n.len() > 0)
-                                                .map(|n| n.parse::<i64>().expect("Tried parsing {n}"))
-                                                .collect();
-
-        return Self
+        let Some((expected_val, operands)) = line.split_once(':') else
+        {
+            return Err(ParseError::new(line_num, 1, "expected a ':' after the test value"));
+        };
+
+        let expected_val: i64 = parse_token(line_num, line, expected_val)?;
+        let operands: Vec<i64> = operands.split(' ')
+                                                .filter(|n| !n.is_empty())
+                                                .map(|n| parse_token(line_num, line, n))
+                                                .collect::<Result<_, _>>()?;
+
+        if operands.is_empty()
+        {
+            return Err(ParseError::new(line_num, line.len() + 1, "expected at least one operand"));
+        }
+
+        return Ok(Self
         {
             m_expected_value: expected_val,
             m_operands: operands
-        };
+        });
     }
 
     fn could_be_valid_mult_or_add(&self) -> bool
@@ -79,10 +88,15 @@ impl TestLine
 
 
 // Problem
-pub fn sum_total_valid_tests_mult_or_add(input: &String) -> i64
+pub fn sum_total_valid_tests_mult_or_add(input: &String) -> Result<i64, ParseError>
 {
-    let tests = parse_tests(&input);
+    let tests = parse_tests(input)?;
+
+    return Ok(sum_valid_tests_mult_or_add(&tests));
+}
 
+pub fn sum_valid_tests_mult_or_add(tests: &Vec<TestLine>) -> i64
+{
     let sum_valid_test: i64 = tests.iter()
                                     .filter(|t| t.could_be_valid_mult_or_add())
                                     .map(|valid_test| valid_test.m_expected_value)
@@ -91,10 +105,15 @@ pub fn sum_total_valid_tests_mult_or_add(input: &String) -> i64
     return sum_valid_test;
 }
 
-pub fn sum_total_valid_tests_mult_or_add_or_concat(input: &String) -> i64
+pub fn sum_total_valid_tests_mult_or_add_or_concat(input: &String) -> Result<i64, ParseError>
 {
-    let tests = parse_tests(&input);
+    let tests = parse_tests(input)?;
 
+    return Ok(sum_valid_tests_mult_or_add_or_concat(&tests));
+}
+
+pub fn sum_valid_tests_mult_or_add_or_concat(tests: &Vec<TestLine>) -> i64
+{
     let sum_valid_test: i64 = tests.iter()
                                     .filter(|t| t.could_be_valid_mult_or_add_or_concat())
                                     .map(|valid_test| valid_test.m_expected_value)
@@ -105,9 +124,9 @@ pub fn sum_total_valid_tests_mult_or_add_or_concat(input: &String) -> i64
 
 
 // Parse
-fn parse_tests(input: &String) -> Vec<TestLine>
+pub fn parse_tests(input: &String) -> Result<Vec<TestLine>, ParseError>
 {
-    return input.lines().into_iter().map(|l| TestLine::from(l)).collect();
+    return numbered_lines(input).map(|(line_num, l)| TestLine::from(line_num, l)).collect();
 }
 
 // Maths
@@ -116,7 +135,7 @@ pub fn concat_base10(mut n1: i64, n2: i64) -> i64
     let digits2 = count_digits(n2);
     for _ in 0..digits2
     {
-        n1 = n1 * 10;
+        n1 *= 10;
     }
 
     return n1 + n2;
@@ -127,7 +146,7 @@ fn count_digits(mut n2: i64) -> i64
     let mut digits = 1;
     while n2 >= 10
     {
-        n2 = n2 / 10;
+        n2 /= 10;
         digits += 1;
     }
 
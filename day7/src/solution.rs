@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem::{self, TestLine};
+
+pub struct Day7;
+
+impl Solution for Day7
+{
+    type Params = NoParams;
+    type Parsed1 = Vec<TestLine>;
+    type Parsed2 = Vec<TestLine>;
+
+    const DAY: u32 = 7;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return problem::parse_tests(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::sum_valid_tests_mult_or_add(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return problem::parse_tests(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::sum_valid_tests_mult_or_add_or_concat(parsed));
+    }
+}
\ No newline at end of file
@@ -2,6 +2,7 @@
 mod tests 
 {
     use crate::problem;
+    use aoc_core::parse_error::ParseError;
 
     const TEST_STR: &str = r"190: 10 19
 3267: 81 40 27
@@ -21,14 +22,14 @@ mod tests
     fn simple_test() 
     {
         let result = problem::sum_total_valid_tests_mult_or_add(&String::from(SIMPLE_STR));
-        assert_eq!(result, 190);
+        assert_eq!(result, Ok(190));
     }
 
     #[test]
     fn simple_concat_test() 
     {
         let result = problem::sum_total_valid_tests_mult_or_add_or_concat(&String::from(SIMPLE_CONCAT_STR));
-        assert_eq!(result, 7290);
+        assert_eq!(result, Ok(7290));
     }
 
     #[test]
@@ -45,7 +46,7 @@ mod tests
     fn part_1_test() 
     {
         let result = problem::sum_total_valid_tests_mult_or_add(&String::from(TEST_STR));
-        assert_eq!(result, 3749);
+        assert_eq!(result, Ok(3749));
     }
 
 
@@ -54,6 +55,13 @@ mod tests
     fn part_2_test() 
     {
         let result = problem::sum_total_valid_tests_mult_or_add_or_concat(&String::from(TEST_STR));
-        assert_eq!(result, 11387);
+        assert_eq!(result, Ok(11387));
+    }
+
+    #[test]
+    fn missing_colon_test() 
+    {
+        let result = problem::sum_total_valid_tests_mult_or_add(&String::from("190: 10 19\n3267 81 40 27"));
+        assert_eq!(result, Err(ParseError::new(2, 1, "expected a ':' after the test value")));
     }
 }
\ No newline at end of file
@@ -1,2 +1 @@
-mod char_grid;
 pub mod problem;
\ No newline at end of file
@@ -0,0 +1,37 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+use aoc_core::char_grid::CharGrid;
+
+use crate::problem::problem;
+
+pub struct Day8;
+
+impl Solution for Day8
+{
+    type Params = NoParams;
+    type Parsed1 = CharGrid;
+    type Parsed2 = CharGrid;
+
+    const DAY: u32 = 8;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return CharGrid::parse(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::count_antinodes(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return CharGrid::parse(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::count_harmonics(parsed));
+    }
+}
\ No newline at end of file
@@ -1,38 +1,44 @@
-struct FileSystem
+use aoc_core::parse_error::ParseError;
+
+// (start, len)
+type Span = (usize, usize);
+
+#[derive(Clone)]
+pub struct FileSystem
 {
-    m_blocks : Vec<i32>,
+    pub m_blocks : Vec<i32>,
 }
 
 impl FileSystem
 {
-    fn from(input: &String) -> Self
+    pub fn from(input: &String) -> Result<Self, ParseError>
     {
         let mut curr_id: i32 = 0;
         let mut blocks : Vec<i32> = Vec::new();
 
-        // consome 2 at a time.
-        let mut char_it = input.chars();
-        while let Some(first) = char_it.next()
+        // Digits alternate between a file size and the gap after it.
+        for (idx, c) in input.trim_end().chars().enumerate()
         {
-            let second = char_it.next().unwrap_or(' ');
+            let Some(size) = c.to_digit(10) else
+            {
+                return Err(ParseError::new(1, idx + 1, format!("expected a digit, found '{c}'")));
+            };
 
-            if let Some(file_size) = first.to_digit(10)
+            if idx % 2 == 0
             {
-                blocks.extend(std::iter::repeat(curr_id).take(file_size as usize));
+                blocks.extend(std::iter::repeat_n(curr_id, size as usize));
             }
-
-            if let Some(blank_gap) = second.to_digit(10)
+            else
             {
-                blocks.extend(std::iter::repeat(-1).take(blank_gap as usize));
+                blocks.extend(std::iter::repeat_n(-1, size as usize));
+                curr_id += 1;
             }
-
-            curr_id += 1;
         }
 
-        Self
+        return Ok(Self
         {
             m_blocks: blocks
-        }
+        });
     }
 
     fn checksum(&self) -> i64
@@ -60,7 +66,7 @@ impl FileSystem
         let mut free_head = 0;
         for block in self.m_blocks.iter().enumerate()
         {
-            if block.1.clone() == -1
+            if *block.1 == -1
             {
                 free_head = block.0;
                 break;
@@ -171,11 +177,11 @@ impl FileSystem
         }
     }
 
-    fn generate_file_free_lists(&self) -> (Vec<(usize, usize)>, Vec<(usize, usize)>)
+    fn generate_file_free_lists(&self) -> (Vec<Span>, Vec<Span>)
     {
         // Find files and find free list. Format is (start, len)
-        let mut free_list : Vec<(usize, usize)> = Vec::new();
-        let mut file_list : Vec<(usize, usize)> = Vec::new();
+        let mut free_list : Vec<Span> = Vec::new();
+        let mut file_list : Vec<Span> = Vec::new();
 
         let mut curr_block_info: (usize, usize) = (0, 0);
         let mut prev_block_id = self.m_blocks[0];
@@ -227,10 +233,16 @@ impl FileSystem
 
 
 //
-pub fn defrag_and_checksum(input: &String) -> i64
+pub fn defrag_and_checksum(input: &String) -> Result<i64, ParseError>
 {
-    let mut file_system = FileSystem::from(&input);
+    let file_system = FileSystem::from(input)?;
 
+    return Ok(checksum_after_defrag(&file_system));
+}
+
+pub fn checksum_after_defrag(file_system: &FileSystem) -> i64
+{
+    let mut file_system = file_system.clone();
     file_system.defrag();
 
     //dbg!(file_system.m_blocks);
@@ -238,10 +250,16 @@ pub fn defrag_and_checksum(input: &String) -> i64
     return file_system.checksum();
 }
 
-pub fn defrag_whole_files_and_checksum(input: &String) -> i64
+pub fn defrag_whole_files_and_checksum(input: &String) -> Result<i64, ParseError>
 {
-    let mut file_system = FileSystem::from(&input);
+    let file_system = FileSystem::from(input)?;
 
+    return Ok(checksum_after_defrag_whole_files(&file_system));
+}
+
+pub fn checksum_after_defrag_whole_files(file_system: &FileSystem) -> i64
+{
+    let mut file_system = file_system.clone();
     file_system.defrag_whole_files();
     //dbg!(&file_system.m_blocks);
 
@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::problem::{self, FileSystem};
+
+pub struct Day9;
+
+impl Solution for Day9
+{
+    type Params = NoParams;
+    type Parsed1 = FileSystem;
+    type Parsed2 = FileSystem;
+
+    const DAY: u32 = 9;
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return FileSystem::from(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::checksum_after_defrag(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return FileSystem::from(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(problem::checksum_after_defrag_whole_files(parsed));
+    }
+}
\ No newline at end of file
@@ -9,13 +9,13 @@ mod tests
     fn part_1_test() 
     {
         let result = problem::defrag_and_checksum(&String::from(TEST_STR));
-        assert_eq!(result, 1928);
+        assert_eq!(result, Ok(1928));
     }
 
     #[test]
     fn part_2_test() 
     {
          let result = problem::defrag_whole_files_and_checksum(&String::from(TEST_STR));
-         assert_eq!(result, 2858);
+         assert_eq!(result, Ok(2858));
     }
 }
\ No newline at end of file
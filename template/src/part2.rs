@@ -1,9 +1,22 @@
-use crate::int_grid::*;
-use crate::char_grid::*;
-use crate::aoc_utils::*;
-use crate::direction::*;
+use aoc_core::int_grid::*;
+use aoc_core::char_grid::*;
+use aoc_core::aoc_utils::*;
+use aoc_core::direction::*;
+use aoc_core::parse_error::*;
 
-pub fn compute_answer(input: &str) -> i32
+pub fn compute_answer(input: &String) -> Result<{{part2_type}}, ParseError>
+{
+    let lines = parse_lines(input)?;
+
+    return Ok(solve(&lines));
+}
+
+pub fn parse_lines(input: &String) -> Result<Vec<String>, ParseError>
+{
+    return Ok(input.lines().map(String::from).collect());
+}
+
+pub fn solve(lines: &Vec<String>) -> {{part2_type}}
 {
     todo!();
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+use aoc_core::config::NoParams;
+use aoc_core::parse_error::ParseError;
+use aoc_core::solution::*;
+
+use crate::{part1, part2};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}}
+{
+    type Params = NoParams;
+    type Parsed1 = Vec<String>;
+    type Parsed2 = Vec<String>;
+
+    const DAY: u32 = {{day}};
+
+    fn parse_part1(input: &String, _params: &NoParams) -> Result<Self::Parsed1, ParseError>
+    {
+        return part1::parse_lines(input);
+    }
+
+    fn solve_part1(parsed: &Self::Parsed1, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part1::solve(parsed));
+    }
+
+    fn parse_part2(input: &String, _params: &NoParams) -> Result<Self::Parsed2, ParseError>
+    {
+        return part2::parse_lines(input);
+    }
+
+    fn solve_part2(parsed: &Self::Parsed2, _params: &NoParams) -> Answer
+    {
+        return Answer::from(part2::solve(parsed));
+    }
+}
\ No newline at end of file
@@ -4,19 +4,19 @@ mod tests
     use crate::part1;
     use crate::part2;
 
-    const TEST_STR: &str = r"";
+    const TEST_STR: &str = {{example}};
 
     #[test]
     fn part_1_test() 
     {
-        let result = part1::compute_answer(TEST_STR);
-        assert_eq!(result, 0);
+        let result = part1::compute_answer(&String::from(TEST_STR));
+        assert_eq!(result, Ok({{part1}}));
     }
 
-    // #[test]
-    // fn part_2_test() 
-    // {
-    //     let result = part2::compute_answer(TEST_STR);
-    //     assert_eq!(result, 0);
-    // }
+    #[test]
+    fn part_2_test() 
+    {
+        let result = part2::compute_answer(&String::from(TEST_STR));
+        assert_eq!(result, Ok({{part2}}));
+    }
 }
\ No newline at end of file